@@ -0,0 +1,155 @@
+//! Benchmarks every part of every day on its puzzle input.
+//!
+//! Run `cargo bench -p aoc` for every day, or `cargo bench -p aoc -- day07` for a single one.
+//! Criterion compares each run with the previous one, or with a named run given by
+//! `-- --save-baseline <name>` and `-- --baseline <name>`.
+//!
+//! Once done, a summary table compares the mean time of every part with the last one measured at
+//! another commit. The times are kept in `target/bench-history.csv`.
+
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    hint::black_box,
+    io::Write,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use aoc::solvers::SOLVERS;
+use common::input;
+use criterion::Criterion;
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+const HISTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/bench-history.csv");
+
+struct Timing {
+    day: u8,
+    part: u8,
+    /// The mean time taken to solve the part.
+    time: Duration,
+}
+
+/// Benchmarks every solver selected on the command line, returning their mean times.
+fn bench(criterion: &mut Criterion) -> Vec<Timing> {
+    let mut timings = Vec::new();
+    let mut days: Vec<u8> = SOLVERS.iter().map(|solver| solver.day).collect();
+    days.dedup();
+    for day in days {
+        let mut group = criterion.benchmark_group(format!("day{day:02}"));
+        for solver in SOLVERS.iter().filter(|solver| solver.day == day) {
+            let path = format!("{WORKSPACE}/{}/{}", solver.directory, input::DEFAULT_PATH);
+            let input = input::read(&path).unwrap_or_else(|error| panic!("{path}: {error}"));
+            let (mut total, mut runs) = (Duration::ZERO, 0);
+            group.bench_function(format!("part{}", solver.part), |bencher| {
+                bencher.iter_custom(|iterations| {
+                    let then = Instant::now();
+                    for _ in 0..iterations {
+                        black_box((solver.solve)(black_box(&input))).unwrap();
+                    }
+                    let elapsed = then.elapsed();
+                    total += elapsed;
+                    runs += iterations;
+                    elapsed
+                })
+            });
+            // Parts filtered out on the command line never run.
+            if runs > 0 {
+                timings.push(Timing {
+                    day,
+                    part: solver.part,
+                    time: total.div_f64(runs as f64),
+                });
+            }
+        }
+        group.finish();
+    }
+    timings
+}
+
+fn commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(WORKSPACE)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Returns the last time recorded for every part at a commit other than |commit|.
+fn previous_times(history: &str, commit: &str) -> HashMap<(u8, u8), Duration> {
+    let mut times = HashMap::new();
+    for line in history.lines() {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [other, day, part, nanoseconds] = fields[..] else {
+            continue;
+        };
+        if other == commit {
+            continue;
+        }
+        if let (Ok(day), Ok(part), Ok(nanoseconds)) =
+            (day.parse(), part.parse(), nanoseconds.parse())
+        {
+            times.insert((day, part), Duration::from_nanos(nanoseconds));
+        }
+    }
+    times
+}
+
+/// Prints the summary table and adds |timings| to the history.
+fn report(timings: &[Timing]) {
+    if timings.is_empty() {
+        return;
+    }
+    let commit = commit();
+    let previous = previous_times(&fs::read_to_string(HISTORY).unwrap_or_default(), &commit);
+
+    println!();
+    println!("Commit {commit}");
+    println!(
+        "{:>4} {:>5} {:>12} {:>12} {:>8}",
+        "Day", "Part", "Time", "Previous", "Change"
+    );
+    for timing in timings {
+        let time = format!("{:.2?}", timing.time);
+        let (before, change) = match previous.get(&(timing.day, timing.part)) {
+            Some(before) => (
+                format!("{before:.2?}"),
+                format!(
+                    "{:+.1}%",
+                    (timing.time.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+                ),
+            ),
+            None => (String::from("-"), String::from("-")),
+        };
+        println!(
+            "{:>4} {:>5} {time:>12} {before:>12} {change:>8}",
+            timing.day, timing.part
+        );
+    }
+
+    let mut history = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY)
+        .unwrap_or_else(|error| panic!("{HISTORY}: {error}"));
+    for timing in timings {
+        writeln!(
+            history,
+            "{commit},{},{},{}",
+            timing.day,
+            timing.part,
+            timing.time.as_nanos()
+        )
+        .unwrap();
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().sample_size(10).configure_from_args();
+    let timings = bench(&mut criterion);
+    criterion.final_summary();
+    report(&timings);
+}
@@ -0,0 +1,144 @@
+//! Watching the frames recorded by the simulations as they happen, redrawn in the terminal.
+//!
+//! Every character cell of the terminal shows two cells of a frame, one above the other, with a
+//! half block coloured in both. The frames are cropped to the terminal, below the name of the
+//! task.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io::{self, Write},
+    sync::Mutex,
+    thread,
+    time::Duration,
+};
+
+use common::{
+    frame::{self, Palette, Recorder},
+    grid::Grid,
+};
+
+/// The size of the terminal when it cannot be known, such as when the standard error is not one.
+const DEFAULT_SIZE: (usize, usize) = (80, 24);
+
+/// Draws the frames of every task on the standard error, waiting between them.
+pub struct Animation {
+    delay: Duration,
+    /// Only the first of every |every| frames is drawn.
+    every: usize,
+    /// The number of frames recorded for every task, whether they were drawn or not.
+    recorded: Mutex<HashMap<String, usize>>,
+}
+
+/// Returns the escape sequence drawing |upper| over |lower| in the following half blocks.
+fn colours(upper: [u8; 3], lower: [u8; 3]) -> String {
+    let [r, g, b] = upper;
+    let [r2, g2, b2] = lower;
+    format!("\x1b[38;2;{r};{g};{b};48;2;{r2};{g2};{b2}m")
+}
+
+/// Returns the escape sequences drawing |task| and as much of |cells| as fits in |width| columns
+/// and |height| lines, from the top left corner of the terminal.
+fn render(task: &str, cells: &Grid<char>, palette: Palette, width: usize, height: usize) -> String {
+    let mut screen = String::from("\x1b[H\x1b[0m");
+    let title: String = task.chars().take(width).collect();
+    let _ = write!(screen, "\x1b[1m{title}\x1b[0m\x1b[K");
+    let columns = cells.width().min(width);
+    let lines = cells.height().div_ceil(2).min(height.saturating_sub(1));
+    let background = palette
+        .first()
+        .map_or(frame::UNKNOWN, |(_, colour)| *colour);
+    for line in 0..lines {
+        screen.push('\n');
+        let mut last = None;
+        for x in 0..columns {
+            let upper = frame::colour(palette, cells[(x, 2 * line)]);
+            let lower = cells
+                .get((x, 2 * line + 1))
+                .map_or(background, |cell| frame::colour(palette, *cell));
+            if last != Some((upper, lower)) {
+                screen.push_str(&colours(upper, lower));
+                last = Some((upper, lower));
+            }
+            screen.push('▀');
+        }
+        screen.push_str("\x1b[0m\x1b[K");
+    }
+    screen.push_str("\x1b[0m\x1b[J");
+    screen
+}
+
+impl Animation {
+    /// Returns an animation waiting |delay| after every frame it draws, and drawing the first of
+    /// every |every| frames.
+    pub fn new(delay: Duration, every: usize) -> Animation {
+        Animation {
+            delay,
+            every: every.max(1),
+            recorded: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Recorder for Animation {
+    fn frame(&self, task: &str, palette: Palette, draw: &mut dyn FnMut() -> Grid<char>) {
+        // The lock is held while drawing so that the frames of different tasks do not interleave.
+        let mut recorded = self.recorded.lock().unwrap();
+        let count = recorded.entry(String::from(task)).or_default();
+        *count += 1;
+        if !(*count - 1).is_multiple_of(self.every) {
+            return;
+        }
+
+        let (width, height) = terminal_size::terminal_size_of(io::stderr()).map_or(
+            DEFAULT_SIZE,
+            |(terminal_size::Width(width), terminal_size::Height(height))| {
+                (width as usize, height as usize)
+            },
+        );
+        let screen = render(task, &draw(), palette, width, height);
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\x1b[?25l{screen}");
+        let _ = stderr.flush();
+        thread::sleep(self.delay);
+    }
+
+    fn finish(&self) -> io::Result<()> {
+        if self.recorded.lock().unwrap().is_empty() {
+            return Ok(());
+        }
+        let mut stderr = io::stderr().lock();
+        write!(stderr, "\x1b[0m\n\x1b[?25h")?;
+        stderr.flush()
+    }
+}
+
+#[test]
+fn tests() {
+    const PALETTE: Palette = &[('.', [0, 0, 0]), ('#', [255, 255, 255])];
+    let mut cells = Grid::new(3, 3, '.');
+    cells[(1, 0)] = '#';
+    cells[(2, 2)] = '#';
+
+    let black = colours([0, 0, 0], [0, 0, 0]);
+    let white_over_black = colours([255, 255, 255], [0, 0, 0]);
+    assert_eq!(
+        render("Task", &cells, PALETTE, 80, 24),
+        format!(
+            "\x1b[H\x1b[0m\x1b[1mTask\x1b[0m\x1b[K\
+             \n{black}▀{white_over_black}▀{black}▀\x1b[0m\x1b[K\
+             \n{black}▀▀{white_over_black}▀\x1b[0m\x1b[K\
+             \x1b[0m\x1b[J"
+        )
+    );
+
+    // Only two columns and the first line of cells fit below the title.
+    assert_eq!(
+        render("Task", &cells, PALETTE, 2, 2),
+        format!(
+            "\x1b[H\x1b[0m\x1b[1mTa\x1b[0m\x1b[K\
+             \n{black}▀{white_over_black}▀\x1b[0m\x1b[K\
+             \x1b[0m\x1b[J"
+        )
+    );
+}
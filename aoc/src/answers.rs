@@ -0,0 +1,56 @@
+//! The known answers to the puzzles, to check the solvers against.
+
+use std::collections::HashMap;
+
+/// The path used when none is given, relative to the root of the workspace.
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+/// Parses the number following |prefix| in |key|, as in day07 or part2.
+fn number(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("Invalid key {key}, expected {prefix} followed by a number"))
+}
+
+impl Answers {
+    /// Parses a table per day, named like day07, mapping part1 and part2 to the answers.
+    pub fn parse(string: &str) -> Result<Answers, String> {
+        let table: toml::Table = string.parse().map_err(|error| format!("{error}"))?;
+        let mut answers = HashMap::new();
+        for (day_key, parts) in table {
+            let day = number(&day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("Expected a table for {day_key}"))?;
+            for (part_key, answer) in parts {
+                let part = number(part_key, "part")?;
+                let answer = answer
+                    .as_str()
+                    .ok_or_else(|| format!("Expected a string for {day_key}.{part_key}"))?;
+                answers.insert((day, part), String::from(answer));
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[test]
+fn tests() {
+    let answers = Answers::parse("[day01]\npart1 = \"24000\"\n\n[day10]\npart2 = '''\n#.\n.#'''\n");
+    let answers = answers.unwrap();
+    assert_eq!(answers.get(1, 1), Some("24000"));
+    assert_eq!(answers.get(1, 2), None);
+    assert_eq!(answers.get(10, 2), Some("#.\n.#"));
+
+    assert!(Answers::parse("[first]\npart1 = \"1\"").is_err());
+    assert!(Answers::parse("[day01]\npart1 = 1").is_err());
+    assert!(Answers::parse("day01 = 1").is_err());
+}
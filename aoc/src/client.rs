@@ -0,0 +1,352 @@
+//! Fetching the puzzle inputs and submitting the answers to the Advent of Code website.
+//!
+//! Every request goes through an |Http| backend, so that tests can answer them locally, and
+//! through a |RateLimiter|, so that the website is not flooded. The inputs are cached on disk
+//! where the solvers read them, and are only fetched once.
+
+use common::input;
+use std::{
+    fmt, fs, io,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The website used when none is given.
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+/// The environment variable holding the session cookie of the logged in user.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+/// The environment variable replacing the website, such as with a local server.
+pub const URL_VARIABLE: &str = "AOC_URL";
+/// The shortest time between two requests used when none is given.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+const YEAR: u16 = 2022;
+
+/// Sends the requests of a |Client|.
+pub trait Http: Send + Sync {
+    /// Returns the body of the page at |url|, requested with the |session| cookie.
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+
+    /// Returns the body of the page at |url| after posting |form| to it with the |session| cookie.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String>;
+}
+
+/// Sends the requests over the network.
+pub struct Web {
+    agent: ureq::Agent,
+}
+
+impl Web {
+    pub fn new() -> Web {
+        Web {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Default for Web {
+    fn default() -> Web {
+        Web::new()
+    }
+}
+
+/// Returns the body of |response|, or the reason why there is none.
+fn body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|error| format!("Could not read {url}: {error}")),
+        Err(ureq::Error::Status(status, response)) => Err(format!(
+            "{url} answered {status} {}",
+            response.status_text()
+        )),
+        Err(error) => Err(format!("Could not reach {url}: {error}")),
+    }
+}
+
+impl Http for Web {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        body(url, response)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form);
+        body(url, response)
+    }
+}
+
+/// Spaces out the requests by at least |interval|, from however many threads.
+pub struct RateLimiter {
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> RateLimiter {
+        RateLimiter {
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Blocks until the next request can be sent.
+    pub fn wait(&self) {
+        let mut last = self.last.lock().unwrap();
+        if let Some(last) = *last {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The last answer was submitted too recently, with how long to wait as the website puts it.
+    TooSoon(String),
+    /// The part is already solved, or the first part is not yet.
+    WrongLevel,
+    /// Anything else the website said.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict in the |page| answering a submission.
+    fn from(page: &str) -> Verdict {
+        let message = page
+            .split_once("<article>")
+            .and_then(|(_, article)| article.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("", |(wait, _)| wait);
+            Verdict::TooSoon(String::from(wait))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(strip_tags(message).trim().to_string())
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Verdict::Wrong => write!(f, "That's not the right answer"),
+            Verdict::TooSoon(wait) => write!(f, "An answer was given too recently, wait {wait}"),
+            Verdict::WrongLevel => write!(f, "This part cannot be answered now"),
+            Verdict::Unknown(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Returns |html| without its tags.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text
+}
+
+pub struct Client {
+    http: Box<dyn Http>,
+    url: String,
+    session: String,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    /// Returns a client of the website at |url|, logged in with the |session| cookie, sending
+    /// requests through |http| at most once per |interval|.
+    pub fn new(http: Box<dyn Http>, url: &str, session: &str, interval: Duration) -> Client {
+        Client {
+            http,
+            url: String::from(url.trim_end_matches('/')),
+            session: String::from(session),
+            limiter: RateLimiter::new(interval),
+        }
+    }
+
+    /// Returns the puzzle input of |day|.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        self.limiter.wait();
+        let url = format!("{}/{YEAR}/day/{day}/input", self.url);
+        self.http.get(&url, &self.session)
+    }
+
+    /// Returns the puzzle input of |day| saved at |path|, first fetching and saving it there if
+    /// it is missing.
+    pub fn cached_input(&self, day: u8, path: &str) -> Result<String, String> {
+        match input::read(path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(format!("Could not read {path}: {error}")),
+        }
+        let input = self.input(day)?;
+        fs::write(path, &input).map_err(|error| format!("Could not write {path}: {error}"))?;
+        Ok(input)
+    }
+
+    /// Submits |answer| to |part| of |day|.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        self.limiter.wait();
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.url);
+        let level = part.to_string();
+        let page = self.http.post(
+            &url,
+            &self.session,
+            &[("level", level.as_str()), ("answer", answer)],
+        )?;
+        Ok(Verdict::from(&page))
+    }
+}
+
+#[test]
+fn tests() {
+    use std::{
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+    };
+
+    // A local server standing in for the website, answering every request with its own line.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for stream in listener.incoming().take(3) {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                head.push(line.trim_end().to_string());
+            }
+            let length = head
+                .iter()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let response = match head[0].as_str() {
+                "GET /2022/day/1/input HTTP/1.1" => "1000\n2000\n",
+                "POST /2022/day/1/answer HTTP/1.1" => {
+                    "<main><article><p>That's not the right answer; your answer is too low.</p>\
+                     </article></main>"
+                }
+                _ => "",
+            };
+            let status = if response.is_empty() {
+                "404 Not Found"
+            } else {
+                "200 OK"
+            };
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+            requests.push((head, String::from_utf8(body).unwrap()));
+        }
+        requests
+    });
+
+    let client = Client::new(Box::new(Web::new()), &url, "secret", Duration::ZERO);
+    let path = env::temp_dir().join(format!("aoc-client-{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+    assert_eq!(
+        client.cached_input(1, path),
+        Ok(String::from("1000\n2000\n"))
+    );
+    // The second time, the input comes from the disk.
+    assert_eq!(
+        client.cached_input(1, path),
+        Ok(String::from("1000\n2000\n"))
+    );
+    fs::remove_file(path).unwrap();
+    assert_eq!(client.submit(1, 2, "42"), Ok(Verdict::TooLow));
+    assert!(client.input(2).unwrap_err().contains("404"));
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0]
+        .0
+        .contains(&String::from("Cookie: session=secret")));
+    assert_eq!(requests[1].1, "level=2&answer=42");
+
+    assert_eq!(
+        Verdict::from("<article><p>That's the right answer!</p></article>"),
+        Verdict::Correct
+    );
+    assert_eq!(
+        Verdict::from("<article>That's not the right answer. Please wait.</article>"),
+        Verdict::Wrong
+    );
+    assert_eq!(
+        Verdict::from("You gave an answer too recently. You have 38s left to wait."),
+        Verdict::TooSoon(String::from("38s"))
+    );
+    assert_eq!(
+        Verdict::from("<article><p>You don't seem to be solving the right level.</p></article>"),
+        Verdict::WrongLevel
+    );
+    assert_eq!(
+        Verdict::from("<article><p>Please <b>log in</b>.</p></article>"),
+        Verdict::Unknown(String::from("Please log in."))
+    );
+
+    let limiter = RateLimiter::new(Duration::from_millis(50));
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.wait();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
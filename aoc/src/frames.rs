@@ -0,0 +1,294 @@
+//! Saving the frames recorded by the simulations as images.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use common::{
+    frame::{self, Palette, Recorder},
+    grid::Grid,
+};
+
+/// How long every frame of an animated GIF is shown, in hundredths of a second.
+const GIF_DELAY: u16 = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    /// A single animated GIF per task.
+    Gif,
+}
+
+/// An animated GIF being written, frame by frame.
+struct Gif {
+    encoder: gif::Encoder<BufWriter<File>>,
+    /// The size in pixels of every frame, set by the first one.
+    width: u16,
+    height: u16,
+}
+
+/// The frames of a task kept so far.
+#[derive(Default)]
+struct Task {
+    /// The number of frames recorded, whether they were kept or not.
+    recorded: usize,
+    /// The number of frames kept.
+    kept: usize,
+    /// The animated GIF of the task, once its first frame is kept.
+    gif: Option<Gif>,
+}
+
+/// Saves the frames of every task into a directory, either as numbered images inside a directory
+/// named after the task, or as an animated GIF named after the task.
+pub struct Writer {
+    directory: PathBuf,
+    format: ImageFormat,
+    /// The width and height in pixels of a cell.
+    scale: usize,
+    /// Only the first of every |every| frames is kept.
+    every: usize,
+    tasks: Mutex<HashMap<String, Arc<Mutex<Task>>>>,
+    /// The first error met while writing, which stops any more frames from being written.
+    error: Mutex<Option<io::Error>>,
+}
+
+/// Returns |task| in lower case, with dashes instead of anything but letters and digits.
+fn file_name(task: &str) -> String {
+    task.split(|char: char| !char.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Returns the colours of the pixels of |cells|, each |scale| pixels wide, as red, green and blue.
+fn pixels<T>(cells: &Grid<T>, scale: usize, colour: impl Fn(&T) -> [u8; 3]) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(cells.width() * cells.height() * scale * scale * 3);
+    for y in 0..cells.height() * scale {
+        for x in 0..cells.width() * scale {
+            pixels.extend(colour(&cells[(x / scale, y / scale)]));
+        }
+    }
+    pixels
+}
+
+fn write_ppm(path: &Path, width: usize, height: usize, pixels: &[u8]) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write!(file, "P6\n{width} {height}\n255\n")?;
+    file.write_all(pixels)?;
+    file.flush()
+}
+
+fn write_png(path: &Path, width: usize, height: usize, pixels: &[u8]) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(path)?),
+        width as u32,
+        height as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(pixels).map_err(io::Error::other)
+}
+
+impl Gif {
+    /// Starts an animated GIF at |path|, as large as |first| frame of |scale| pixels per cell.
+    fn create(path: &Path, first: &Grid<char>, palette: Palette, scale: usize) -> io::Result<Gif> {
+        let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "The frames are too large");
+        let width = u16::try_from(first.width() * scale).map_err(|_| too_large())?;
+        let height = u16::try_from(first.height() * scale).map_err(|_| too_large())?;
+
+        // The cells missing from the palette take the last colour.
+        let colours: Vec<u8> = palette
+            .iter()
+            .map(|(_, colour)| *colour)
+            .chain([frame::UNKNOWN])
+            .flatten()
+            .collect();
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &colours).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(Gif {
+            encoder,
+            width,
+            height,
+        })
+    }
+
+    /// Writes |cells| as the next frame. Smaller frames are padded with the background, and
+    /// larger ones cropped to the size of the first.
+    fn write(&mut self, cells: &Grid<char>, palette: Palette, scale: usize) -> io::Result<()> {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut buffer = vec![0; width * height];
+        for (point, cell) in cells.iter() {
+            if (point.0 + 1) * scale > width || (point.1 + 1) * scale > height {
+                continue;
+            }
+            let index = palette
+                .iter()
+                .position(|(other, _)| other == cell)
+                .unwrap_or(palette.len()) as u8;
+            for y in point.1 * scale..(point.1 + 1) * scale {
+                buffer[y * width + point.0 * scale..y * width + (point.0 + 1) * scale].fill(index);
+            }
+        }
+        let frame = gif::Frame {
+            width: self.width,
+            height: self.height,
+            delay: GIF_DELAY,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+impl Writer {
+    /// Returns a writer into |directory|, drawing every cell |scale| pixels wide and keeping the
+    /// first of every |every| frames.
+    pub fn new(directory: &str, format: ImageFormat, scale: usize, every: usize) -> Writer {
+        Writer {
+            directory: PathBuf::from(directory),
+            format,
+            scale: scale.max(1),
+            every: every.max(1),
+            tasks: Mutex::new(HashMap::new()),
+            error: Mutex::new(None),
+        }
+    }
+
+    fn write(
+        &self,
+        task: &str,
+        index: usize,
+        cells: &Grid<char>,
+        palette: Palette,
+    ) -> io::Result<()> {
+        let directory = self.directory.join(file_name(task));
+        fs::create_dir_all(&directory)?;
+        let extension = match self.format {
+            ImageFormat::Ppm => "ppm",
+            _ => "png",
+        };
+        let path = directory.join(format!("{index:05}.{extension}"));
+        let pixels = pixels(cells, self.scale, |cell| frame::colour(palette, *cell));
+        let (width, height) = (cells.width() * self.scale, cells.height() * self.scale);
+        match self.format {
+            ImageFormat::Ppm => write_ppm(&path, width, height, &pixels),
+            _ => write_png(&path, width, height, &pixels),
+        }
+    }
+}
+
+impl Recorder for Writer {
+    fn frame(&self, task: &str, palette: Palette, draw: &mut dyn FnMut() -> Grid<char>) {
+        if self.error.lock().unwrap().is_some() {
+            return;
+        }
+        // Every task is only locked on its own, so that tasks running in parallel are written in
+        // parallel too.
+        let state = Arc::clone(
+            self.tasks
+                .lock()
+                .unwrap()
+                .entry(String::from(task))
+                .or_default(),
+        );
+        let mut state = state.lock().unwrap();
+        state.recorded += 1;
+        if !(state.recorded - 1).is_multiple_of(self.every) {
+            return;
+        }
+        state.kept += 1;
+        let index = state.kept - 1;
+
+        let cells = draw();
+        let result = if self.format == ImageFormat::Gif {
+            // The frames are written as they come, so that they are never all kept in memory.
+            let gif = match &mut state.gif {
+                Some(gif) => Ok(gif),
+                None => fs::create_dir_all(&self.directory)
+                    .and_then(|()| {
+                        let path = self.directory.join(format!("{}.gif", file_name(task)));
+                        Gif::create(&path, &cells, palette, self.scale)
+                    })
+                    .map(|gif| state.gif.insert(gif)),
+            };
+            gif.and_then(|gif| gif.write(&cells, palette, self.scale))
+        } else {
+            drop(state);
+            self.write(task, index, &cells, palette)
+        };
+        if let Err(error) = result {
+            self.error.lock().unwrap().get_or_insert(error);
+        }
+    }
+
+    fn finish(&self) -> io::Result<()> {
+        if let Some(error) = self.error.lock().unwrap().take() {
+            return Err(error);
+        }
+        for task in self.tasks.lock().unwrap().values() {
+            if let Some(gif) = task.lock().unwrap().gif.take() {
+                gif.encoder
+                    .into_inner()
+                    .map_err(io::Error::other)?
+                    .flush()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn tests() {
+    use std::env;
+
+    assert_eq!(
+        file_name("Pouring sand, onto the floor"),
+        "pouring-sand-onto-the-floor"
+    );
+
+    const PALETTE: Palette = &[('.', [0, 0, 0]), ('#', [255, 255, 255])];
+    let small = Grid::new(2, 1, '#');
+    let large = Grid::new(3, 2, '.');
+    let directory = env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+    let directory = directory.to_str().unwrap();
+
+    for format in [ImageFormat::Ppm, ImageFormat::Png, ImageFormat::Gif] {
+        let writer = Writer::new(directory, format, 2, 2);
+        for _ in 0..2 {
+            writer.frame("Some task", PALETTE, &mut || small.clone());
+        }
+        writer.frame("Some task", PALETTE, &mut || large.clone());
+        writer.finish().unwrap();
+    }
+
+    // Only the first and the third frames are kept.
+    let ppm = fs::read(format!("{directory}/some-task/00000.ppm")).unwrap();
+    assert_eq!(&ppm[..11], b"P6\n4 2\n255\n");
+    assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+    assert!(ppm[11..].iter().all(|value| *value == 255));
+    assert!(Path::new(&format!("{directory}/some-task/00001.ppm")).exists());
+    assert!(!Path::new(&format!("{directory}/some-task/00002.ppm")).exists());
+
+    let png = fs::read(format!("{directory}/some-task/00001.png")).unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+
+    // The GIF is as large as the first frame, 2 by 1 cells of 2 pixels, and ends with a trailer.
+    let gif = fs::read(format!("{directory}/some-task.gif")).unwrap();
+    assert_eq!(&gif[..6], b"GIF89a");
+    assert_eq!(&gif[6..10], &[4, 0, 2, 0]);
+    assert_eq!(gif.last(), Some(&0x3b));
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,7 @@
+//! The solvers of every day, shared by the runner and the benchmarks.
+
+pub mod animation;
+pub mod answers;
+pub mod client;
+pub mod frames;
+pub mod solvers;
@@ -0,0 +1,557 @@
+//! Runs the solvers for any day of the calendar.
+//!
+//! Usage: `aoc run <day|all> [--part <1|2>] [--input <path|->] [--check] [--format <text|json>]
+//! [--verbose|--progress] [--jobs <jobs>] [--frames <directory> [--frame-format <ppm|png|gif>]
+//! [--frame-scale <pixels>] | --animate [--frame-delay <ms>]] [--frame-every <n>]`,
+//! `aoc fetch <day|all>`,
+//! `aoc submit <day> <part> [--answer <answer>]` or `aoc generate <day> [--size <size>] [--seed <seed>]`
+//!
+//! If no part is given, every part of the day is run. If no input is given, the `input.txt` inside
+//! the day's crate is used, wherever `aoc` runs from. An input of `-` reads the puzzle input from
+//! the standard input.
+//!
+//! When the `AOC_SESSION` environment variable holds the session cookie of the website, missing
+//! inputs are first downloaded into the day's crate. `fetch` only downloads them, and `submit`
+//! sends the answer of a part to the website, solving it first if no answer is given. Setting
+//! `AOC_URL` sends the requests to another server, such as a local one for testing.
+//!
+//! With `--check`, every answer is compared with the known answer in `answers.toml`, and the
+//! process fails if any of them is wrong.
+//!
+//! With `--format json`, every part is reported on its own line as a JSON object holding its
+//! `day`, `part`, `answer` and `elapsed_ms`. When checking, the objects also hold the `expected`
+//! answer and whether it is `correct`, and the `answer` is null along with an `error` if solving
+//! failed.
+//!
+//! The solvers are quiet by default. `--verbose` writes their progress and diagnostics to the
+//! standard error, and `--progress` draws progress bars there instead.
+//!
+//! The parts run in parallel on a pool of threads, one per CPU unless `--jobs` says otherwise, and
+//! the days also share the pool with the work inside them. The answers are still reported in
+//! calendar order, each as soon as every part before it is done.
+//!
+//! With `--frames`, the simulations of days 9, 10, 14, 17, 23 and 24 save their states into the
+//! directory: numbered PNG or PPM images inside a directory per simulation, or an animated GIF per
+//! simulation, as large as its first frame.
+//! `--frame-every` only keeps the first of every n frames, and `--frame-scale` sets the size of a
+//! cell in pixels, 4 by default.
+//!
+//! With `--animate`, the simulations of days 9, 10, 14, 17 and 23 are instead redrawn in colour on
+//! the standard error as they run, waiting `--frame-delay` milliseconds after every frame, 50 by
+//! default. Unless `--jobs` says otherwise, the parts then run one at a time.
+//!
+//! `generate` writes a random puzzle input for the day to the standard output, which can be given
+//! back with `--input -`. The same size and seed always give the same input. The size defaults to
+//! 20 and the seed to 0.
+
+use std::{
+    collections::BTreeMap,
+    env, process,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    animation::Animation,
+    answers::{self, Answers},
+    client::{self, Client, Verdict, Web},
+    frames::{ImageFormat, Writer},
+    solvers::{Solver, SOLVERS},
+};
+use common::{
+    error::{self, ParseError},
+    frame::{self, Recorder},
+    input,
+    progress::{self, Observer, ProgressBar, Verbose},
+};
+use rayon::prelude::*;
+use serde_json::{json, Value};
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--check] \
+                     [--format <text|json>] [--verbose|--progress] [--jobs <jobs>] \
+                     [--frames <directory> [--frame-format <ppm|png|gif>] \
+                     [--frame-scale <pixels>] | --animate [--frame-delay <ms>]] \
+                     [--frame-every <n>]\n       \
+                     aoc fetch <day|all>\n       \
+                     aoc submit <day> <part> [--answer <answer>]\n       \
+                     aoc generate <day> [--size <size>] [--seed <seed>]";
+
+const DEFAULT_SIZE: usize = 20;
+/// The width and height in pixels of a cell of the frames, if not given.
+const DEFAULT_SCALE: usize = 4;
+/// How long every animated frame is shown, if not given.
+const DEFAULT_DELAY: Duration = Duration::from_millis(50);
+/// The root of the workspace, which holds the crate of every day and the known answers.
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Args {
+    /// The day to run, or None to run every day.
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    check: bool,
+    format: Format,
+    /// Where the solvers report their progress, if anywhere.
+    observer: Option<Box<dyn Observer>>,
+    /// The number of threads to run on, or None for one per CPU.
+    jobs: Option<usize>,
+    /// Where the simulations save their frames, if anywhere.
+    recorder: Option<Box<dyn Recorder>>,
+}
+
+enum Command {
+    Run(Args),
+    /// Downloads the input of a day, or of every day if None.
+    Fetch(Option<u8>),
+    Submit {
+        day: u8,
+        part: u8,
+        answer: Option<String>,
+    },
+    Generate {
+        day: u8,
+        size: usize,
+        seed: u64,
+    },
+}
+
+fn parse_command(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_args(args).map(Command::Run),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("generate") => parse_generate(args),
+        Some(command) => Err(format!("Unknown command {command}")),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next().ok_or("Missing day")?.as_str() {
+        "all" => None,
+        day => Some(day.parse().map_err(|_| format!("Invalid day {day}"))?),
+    };
+    match args.next() {
+        Some(arg) => Err(format!("Unknown argument {arg}")),
+        None => Ok(Command::Fetch(day)),
+    }
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = args.next().ok_or("Missing day")?;
+    let day = day.parse().map_err(|_| format!("Invalid day {day}"))?;
+    let part = args.next().ok_or("Missing part")?;
+    let part = part.parse().map_err(|_| format!("Invalid part {part}"))?;
+    let mut answer = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answer" => answer = Some(args.next().ok_or("Missing value for --answer")?),
+            _ => return Err(format!("Unknown argument {arg}")),
+        }
+    }
+    Ok(Command::Submit { day, part, answer })
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = args.next().ok_or("Missing day")?;
+    let day = day.parse().map_err(|_| format!("Invalid day {day}"))?;
+    let mut size = DEFAULT_SIZE;
+    let mut seed = 0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let value = args.next().ok_or("Missing value for --size")?;
+                size = value.parse().map_err(|_| format!("Invalid size {value}"))?;
+            }
+            "--seed" => {
+                let value = args.next().ok_or("Missing value for --seed")?;
+                seed = value.parse().map_err(|_| format!("Invalid seed {value}"))?;
+            }
+            _ => return Err(format!("Unknown argument {arg}")),
+        }
+    }
+    Ok(Command::Generate { day, size, seed })
+}
+
+/// Parses the value of |option|, which must be a positive number.
+fn parse_positive(args: &mut impl Iterator<Item = String>, option: &str) -> Result<usize, String> {
+    let value = args
+        .next()
+        .ok_or_else(|| format!("Missing value for {option}"))?;
+    value
+        .parse()
+        .ok()
+        .filter(|value| *value > 0)
+        .ok_or_else(|| format!("Invalid value for {option}: {value}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let day = match args.next().ok_or("Missing day")?.as_str() {
+        "all" => None,
+        day => Some(day.parse().map_err(|_| format!("Invalid day {day}"))?),
+    };
+
+    let mut part = None;
+    let mut input = None;
+    let mut check = false;
+    let mut format = Format::Text;
+    let mut observer: Option<Box<dyn Observer>> = None;
+    let mut jobs = None;
+    let mut frames = None;
+    let mut frame_format = ImageFormat::Png;
+    let mut frame_every = 1;
+    let mut frame_scale = DEFAULT_SCALE;
+    let mut animate = false;
+    let mut frame_delay = DEFAULT_DELAY;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                part = Some(value.parse().map_err(|_| format!("Invalid part {value}"))?);
+            }
+            "--input" => input = Some(args.next().ok_or("Missing value for --input")?),
+            "--check" => check = true,
+            "--verbose" => observer = Some(Box::new(Verbose)),
+            "--progress" => observer = Some(Box::new(ProgressBar)),
+            "--format" => {
+                format = match args.next().ok_or("Missing value for --format")?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    value => return Err(format!("Invalid format {value}")),
+                }
+            }
+            "--jobs" => jobs = Some(parse_positive(&mut args, "--jobs")?),
+            "--frames" => frames = Some(args.next().ok_or("Missing value for --frames")?),
+            "--frame-format" => {
+                frame_format = match args
+                    .next()
+                    .ok_or("Missing value for --frame-format")?
+                    .as_str()
+                {
+                    "ppm" => ImageFormat::Ppm,
+                    "png" => ImageFormat::Png,
+                    "gif" => ImageFormat::Gif,
+                    value => return Err(format!("Invalid frame format {value}")),
+                }
+            }
+            "--frame-every" => frame_every = parse_positive(&mut args, "--frame-every")?,
+            "--frame-scale" => frame_scale = parse_positive(&mut args, "--frame-scale")?,
+            "--animate" => animate = true,
+            "--frame-delay" => {
+                let value = args.next().ok_or("Missing value for --frame-delay")?;
+                let delay = value
+                    .parse()
+                    .map_err(|_| format!("Invalid frame delay {value}"))?;
+                frame_delay = Duration::from_millis(delay);
+            }
+            _ => return Err(format!("Unknown argument {arg}")),
+        }
+    }
+    if day.is_none() && input.is_some() {
+        return Err(String::from("An input can only be given for a single day"));
+    }
+    if animate && frames.is_some() {
+        return Err(String::from("Frames cannot be both animated and saved"));
+    }
+    // Animated parts take turns, rather than fighting over the terminal.
+    if animate && jobs.is_none() {
+        jobs = Some(1);
+    }
+    let recorder: Option<Box<dyn Recorder>> = if animate {
+        Some(Box::new(Animation::new(frame_delay, frame_every)))
+    } else {
+        frames.map(|directory| -> Box<dyn Recorder> {
+            Box::new(Writer::new(
+                &directory,
+                frame_format,
+                frame_scale,
+                frame_every,
+            ))
+        })
+    };
+    Ok(Args {
+        day,
+        part,
+        input,
+        check,
+        format,
+        observer,
+        jobs,
+        recorder,
+    })
+}
+
+fn read_or_exit(path: &str) -> String {
+    input::read(path).unwrap_or_else(|error| {
+        eprintln!("Could not read {path}: {error}");
+        process::exit(1);
+    })
+}
+
+/// Returns a client of the website, or None if there is no session to log in with.
+fn client() -> Option<Client> {
+    let session = env::var(client::SESSION_VARIABLE).ok()?;
+    let url = env::var(client::URL_VARIABLE).unwrap_or_else(|_| String::from(client::DEFAULT_URL));
+    Some(Client::new(
+        Box::new(Web::new()),
+        &url,
+        &session,
+        client::DEFAULT_INTERVAL,
+    ))
+}
+
+fn client_or_exit() -> Client {
+    client().unwrap_or_else(|| {
+        eprintln!(
+            "Set {} to the session cookie of the website",
+            client::SESSION_VARIABLE
+        );
+        process::exit(1);
+    })
+}
+
+/// Reads the puzzle input in the crate of |solver|, first downloading it with |client| if given.
+fn input_or_exit(solver: &Solver, client: Option<&Client>) -> String {
+    let path = format!("{WORKSPACE}/{}/{}", solver.directory, input::DEFAULT_PATH);
+    match client {
+        Some(client) => client
+            .cached_input(solver.day, &path)
+            .unwrap_or_else(|error| {
+                eprintln!("{error}");
+                process::exit(1);
+            }),
+        None => read_or_exit(&path),
+    }
+}
+
+fn answers_or_exit() -> Answers {
+    let path = format!("{WORKSPACE}/{}", answers::DEFAULT_PATH);
+    Answers::parse(&read_or_exit(&path)).unwrap_or_else(|error| {
+        eprintln!("Could not parse {path}: {error}");
+        process::exit(1);
+    })
+}
+
+/// Returns the JSON record of the answer of |solver|.
+fn record(solver: &Solver, answer: Option<&str>, elapsed: Duration) -> Value {
+    json!({
+        "day": solver.day,
+        "part": solver.part,
+        "answer": answer,
+        "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+    })
+}
+
+/// Compares the answer of |solver| with the known one and reports it. Returns whether it matched.
+fn check(
+    solver: &Solver,
+    result: Result<String, ParseError>,
+    elapsed: Duration,
+    answers: &Answers,
+    format: Format,
+) -> bool {
+    let expected = answers.get(solver.day, solver.part);
+    let correct = matches!((&result, expected), (Ok(answer), Some(expected)) if answer == expected);
+    if format == Format::Json {
+        let mut record = record(solver, result.as_deref().ok(), elapsed);
+        record["expected"] = json!(expected);
+        record["correct"] = json!(correct);
+        if let Err(error) = &result {
+            record["error"] = json!(error.to_string());
+        }
+        println!("{record}");
+        return correct;
+    }
+
+    let verdict = match (result, expected) {
+        (Err(error), _) => format!("failed: {error}"),
+        (Ok(answer), None) => format!("no known answer, got {answer}"),
+        (Ok(_), Some(_)) if correct => String::from("ok"),
+        (Ok(answer), Some(expected)) => format!("got {answer}, expected {expected}"),
+    };
+    println!(
+        "Day {}, part {}: {verdict} ({elapsed:?})",
+        solver.day, solver.part
+    );
+    correct
+}
+
+/// Downloads the missing input of |day|, or of every day if None.
+fn fetch(day: Option<u8>) {
+    let client = client_or_exit();
+    let mut directories = Vec::new();
+    for solver in SOLVERS {
+        if day.is_some_and(|day| day != solver.day) || directories.contains(&solver.directory) {
+            continue;
+        }
+        input_or_exit(solver, Some(&client));
+        println!(
+            "Day {}: {}/{}",
+            solver.day,
+            solver.directory,
+            input::DEFAULT_PATH
+        );
+        directories.push(solver.directory);
+    }
+    if directories.is_empty() {
+        eprintln!("There is no solver for day {}", day.unwrap());
+        process::exit(1);
+    }
+}
+
+/// Submits |answer| to |part| of |day|, or the answer of its solver if None.
+fn submit(day: u8, part: u8, answer: Option<String>) {
+    let client = client_or_exit();
+    let answer = answer.unwrap_or_else(|| {
+        let solver = SOLVERS
+            .iter()
+            .find(|solver| solver.day == day && solver.part == part)
+            .unwrap_or_else(|| {
+                eprintln!("There is no solver for day {day} part {part}");
+                process::exit(1);
+            });
+        error::or_exit((solver.solve)(&input_or_exit(solver, Some(&client))))
+    });
+    let verdict = client.submit(day, part, &answer).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!("Day {day}, part {part}: {answer}: {verdict}");
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let command = parse_command(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+    let mut args = match command {
+        Command::Run(args) => args,
+        Command::Fetch(day) => {
+            fetch(day);
+            return;
+        }
+        Command::Submit { day, part, answer } => {
+            submit(day, part, answer);
+            return;
+        }
+        Command::Generate { day, size, seed } => {
+            match generate::generate(day, size, seed) {
+                Some(input) => print!("{input}"),
+                None => {
+                    eprintln!("There is no generator for day {day}");
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+    };
+    if let Some(observer) = args.observer.take() {
+        progress::set_observer(observer);
+    }
+    if let Some(recorder) = args.recorder.take() {
+        frame::set_recorder(recorder);
+    }
+    if let Some(jobs) = args.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()
+            .expect("The thread pool is only built once");
+    }
+
+    let solvers: Vec<_> = SOLVERS
+        .iter()
+        .filter(|solver| args.day.is_none_or(|day| day == solver.day))
+        .filter(|solver| args.part.is_none_or(|part| part == solver.part))
+        .collect();
+    if solvers.is_empty() {
+        match (args.day, args.part) {
+            (Some(day), Some(part)) => eprintln!("There is no solver for day {day} part {part}"),
+            (Some(day), None) => eprintln!("There is no solver for day {day}"),
+            (None, _) => eprintln!("There is no solver for part {}", args.part.unwrap()),
+        }
+        process::exit(1);
+    }
+
+    let answers = args.check.then(answers_or_exit);
+    let then = Instant::now();
+    let mut correct = 0;
+    // The standard input can only be read once, so an explicit input is shared by every part.
+    let shared_input = args.input.as_deref().map(read_or_exit);
+    let client = client();
+    // Every input is read up front, so that a missing one fails before anything is solved.
+    let inputs: Vec<String> = solvers
+        .iter()
+        .map(|solver| match &shared_input {
+            Some(input) => input.clone(),
+            None => input_or_exit(solver, client.as_ref()),
+        })
+        .collect();
+
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            solvers.par_iter().zip(&inputs).enumerate().for_each_with(
+                sender,
+                |sender, (index, (solver, input))| {
+                    let solved = Instant::now();
+                    let result = (solver.solve)(input);
+                    sender.send((index, result, solved.elapsed())).unwrap();
+                },
+            );
+        });
+
+        // The parts finish in any order, so each waits for the ones before it to be reported.
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (index, result, elapsed) in receiver {
+            finished.insert(index, (result, elapsed));
+            while let Some((result, elapsed)) = finished.remove(&next) {
+                let solver = solvers[next];
+                next += 1;
+                match &answers {
+                    Some(answers) => {
+                        correct += check(solver, result, elapsed, answers, args.format) as usize;
+                    }
+                    None => {
+                        let answer = error::or_exit(result);
+                        match args.format {
+                            Format::Text => {
+                                println!("Day {}, part {}: {answer}", solver.day, solver.part)
+                            }
+                            Format::Json => println!("{}", record(solver, Some(&answer), elapsed)),
+                        }
+                    }
+                }
+            }
+        }
+    });
+    if let Err(error) = frame::finish() {
+        eprintln!("Could not save the frames: {error}");
+        process::exit(1);
+    }
+
+    if answers.is_some() {
+        if args.format == Format::Json {
+            process::exit((correct != solvers.len()) as i32);
+        }
+        println!(
+            "{correct} of {} answers are correct ({:?})",
+            solvers.len(),
+            then.elapsed()
+        );
+        if correct != solvers.len() {
+            process::exit(1);
+        }
+    }
+}
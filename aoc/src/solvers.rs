@@ -0,0 +1,107 @@
+use common::error::ParseError;
+
+/// Solves a single part of a single day.
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    /// The crate the solver lives in, which also holds the puzzle input.
+    pub directory: &'static str,
+    /// Returns the answer for the given puzzle input.
+    pub solve: fn(&str) -> Result<String, ParseError>,
+}
+
+const fn solver(
+    day: u8,
+    part: u8,
+    directory: &'static str,
+    solve: fn(&str) -> Result<String, ParseError>,
+) -> Solver {
+    Solver {
+        day,
+        part,
+        directory,
+        solve,
+    }
+}
+
+/// Converts the answer of a solver to a string.
+fn answer<T: ToString>(result: Result<T, ParseError>) -> Result<String, ParseError> {
+    result.map(|answer| answer.to_string())
+}
+
+/// Every solver, in calendar order.
+pub const SOLVERS: &[Solver] = &[
+    solver(1, 1, "day01", |input| answer(day01::part1(input))),
+    solver(1, 2, "day01", |input| answer(day01::part2(input))),
+    solver(2, 1, "day02", |input| answer(day02::part1(input))),
+    solver(2, 2, "day02", |input| answer(day02::part2(input))),
+    solver(3, 1, "day03", |input| answer(day03::part1(input))),
+    solver(3, 2, "day03", |input| answer(day03::part2(input))),
+    solver(4, 1, "day04", |input| answer(day04::part1(input))),
+    solver(4, 2, "day04", |input| answer(day04::part2(input))),
+    solver(5, 1, "day05", |input| answer(day05::part1(input))),
+    solver(5, 2, "day05", |input| answer(day05::part2(input))),
+    solver(6, 1, "day06", |input| answer(day06::part1(input))),
+    solver(6, 2, "day06", |input| answer(day06::part2(input))),
+    solver(7, 1, "day07", |input| answer(day07::part1(input))),
+    solver(7, 2, "day07", |input| answer(day07::part2(input))),
+    solver(8, 1, "day08", |input| answer(day08::part1(input))),
+    solver(8, 2, "day08", |input| answer(day08::part2(input))),
+    solver(9, 1, "day09", |input| answer(day09::part1(input))),
+    solver(9, 2, "day09", |input| answer(day09::part2(input))),
+    solver(10, 1, "day10", |input| answer(day10::part1(input))),
+    solver(10, 2, "day10", |input| answer(day10::part2(input))),
+    solver(11, 1, "day11", |input| answer(day11::part1(input))),
+    solver(11, 2, "day11", |input| answer(day11::part2(input))),
+    solver(12, 1, "day12", |input| answer(day12::part1(input))),
+    solver(12, 2, "day12", |input| answer(day12::part2(input))),
+    solver(13, 1, "day13", |input| answer(day13::part1(input))),
+    solver(13, 2, "day13", |input| answer(day13::part2(input))),
+    solver(14, 1, "day14", |input| answer(day14::part1(input))),
+    solver(14, 2, "day14", |input| answer(day14::part2(input))),
+    solver(15, 1, "day15", |input| answer(day15::part1(input))),
+    solver(15, 2, "day15", |input| answer(day15::part2(input))),
+    solver(16, 1, "day16", |input| answer(day16::part1(input))),
+    solver(16, 2, "day16", |input| answer(day16::part2(input))),
+    solver(17, 1, "day17", |input| answer(day17::part1(input))),
+    solver(17, 2, "day17", |input| answer(day17::part2(input))),
+    solver(18, 1, "day18", |input| answer(day18::part1(input))),
+    solver(18, 2, "day18", |input| answer(day18::part2(input))),
+    solver(19, 1, "day19", |input| answer(day19::part1(input))),
+    solver(19, 2, "day19", |input| answer(day19::part2(input))),
+    solver(20, 1, "day20", |input| answer(day20::part1(input))),
+    solver(20, 2, "day20", |input| answer(day20::part2(input))),
+    solver(21, 1, "day21", |input| answer(day21::part1(input))),
+    solver(21, 2, "day21", |input| answer(day21::part2(input))),
+    solver(22, 1, "day22", |input| answer(day22::part1(input))),
+    solver(22, 2, "day22_part2", |input| {
+        answer(day22_part2::part2(input))
+    }),
+    solver(23, 1, "day23", |input| answer(day23::part1(input))),
+    solver(23, 2, "day23", |input| answer(day23::part2(input))),
+    solver(24, 1, "day24", |input| answer(day24::part1(input))),
+    solver(24, 2, "day24", |input| answer(day24::part2(input))),
+    solver(25, 1, "day25", |input| answer(day25::part1(input))),
+];
+
+#[test]
+fn tests() {
+    // Scanning millions of rows for the sensors and the blueprints is too slow without
+    // optimisations.
+    let slow = [15, 19];
+    for solver in SOLVERS {
+        if slow.contains(&solver.day) {
+            continue;
+        }
+        for seed in 0..3 {
+            let input = generate::generate(solver.day, 5, seed).unwrap();
+            let result = (solver.solve)(&input);
+            assert!(
+                result.is_ok(),
+                "Day {} part {} failed on seed {seed}",
+                solver.day,
+                solver.part
+            );
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Detection of cycles in long simulations, to extrapolate their outcome.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// A simulation whose states repeat after some iterations.
+#[derive(Debug)]
+pub struct Cycle {
+    /// The first iteration of the cycle.
+    pub start: usize,
+    /// The number of iterations after which the states repeat, or 0 if they did not repeat within
+    /// the iterations simulated.
+    pub period: usize,
+    /// The metric at every iteration, until the cycle repeated for the first time.
+    values: Vec<i64>,
+}
+
+impl Cycle {
+    /// Returns a cycle that was never found, along with the metric at every iteration simulated.
+    fn none(values: Vec<i64>) -> Cycle {
+        Cycle {
+            start: values.len(),
+            period: 0,
+            values,
+        }
+    }
+
+    /// Returns the metric at |iteration|, assuming it changes by the same amount every period.
+    pub fn value(&self, iteration: usize) -> i64 {
+        if iteration < self.values.len() {
+            return self.values[iteration];
+        }
+        assert!(self.period > 0, "No cycle was found before {iteration}");
+        let periods = (iteration - self.start) / self.period;
+        let offset = (iteration - self.start) % self.period;
+        let growth = self.values[self.start + self.period] - self.values[self.start];
+        self.values[self.start + offset] + periods as i64 * growth
+    }
+}
+
+/// Advances |state| with |step| until |key| returns a key it already returned before, measuring
+/// |metric| at every iteration. The key must capture everything that decides the future steps.
+///
+/// The simulation stops anyway after |iterations|, as the metric is then known without a cycle.
+pub fn detect<S, K: Eq + Hash>(
+    iterations: usize,
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+) -> Cycle {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut values = Vec::new();
+    loop {
+        let iteration = values.len();
+        values.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), iteration) {
+            return Cycle {
+                start,
+                period: iteration - start,
+                values,
+            };
+        }
+        if iteration == iterations {
+            return Cycle::none(values);
+        }
+        step(&mut state);
+    }
+}
+
+#[test]
+fn tests() {
+    // A number that jumps around below 10, along with the sum of the numbers so far.
+    let step = |(number, sum): &mut (i64, i64)| {
+        *number = (*number * *number + 1) % 10;
+        *sum += *number;
+    };
+    let cycle = detect(100, (3, 0), step, |(number, _)| *number, |(_, sum)| *sum);
+    // 3, 0, 1, 2, 5, 6, 7, 0...
+    assert_eq!((cycle.start, cycle.period), (1, 6));
+
+    let mut state = (3, 0);
+    for iteration in 0..100 {
+        assert_eq!(cycle.value(iteration), state.1);
+        step(&mut state);
+    }
+
+    // Without a key that repeats, the metric is only known until the iterations asked for.
+    let cycle = detect(
+        5,
+        0,
+        |number| *number += 1,
+        |number| *number,
+        |number| *number,
+    );
+    assert_eq!(cycle.period, 0);
+    assert_eq!(cycle.value(5), 5);
+}
@@ -0,0 +1,173 @@
+//! Errors reported while parsing puzzle inputs.
+
+use std::{error::Error, fmt::Display, process, str::FromStr};
+
+/// An error found while parsing a puzzle input, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// The line of the token, starting at 1.
+    pub line: usize,
+    /// The column of the token in characters, starting at 1.
+    pub column: usize,
+    /// A description of what the parser expected to find.
+    pub expected: String,
+    /// The token found instead. Empty if the line or the input ended early.
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}, column {}: expected {}, ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            f.write_str("found nothing")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The puzzle input of a day, used to locate the tokens errors point at.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Source<'a> {
+        Source { day, input }
+    }
+
+    /// Creates an error pointing at |token|, which must be a slice of the input.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= self.input.len())
+            .expect("The token must be a slice of the input");
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: token.to_string(),
+        }
+    }
+
+    /// Creates an error pointing right after |string|, for when a token is missing from it.
+    pub fn missing(&self, string: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&string[string.len()..], expected)
+    }
+
+    /// Creates an error pointing at the character at byte |index| of |string|.
+    pub fn char_error(
+        &self,
+        string: &str,
+        index: usize,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        let length = string[index..].chars().next().map_or(0, char::len_utf8);
+        self.error(&string[index..index + length], expected)
+    }
+
+    /// Splits |string| around the first |pattern|, or returns an error expecting |pattern|.
+    pub fn split_once(
+        &self,
+        string: &'a str,
+        pattern: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        string
+            .split_once(pattern)
+            .ok_or_else(|| self.missing(string, format!("{pattern:?}")))
+    }
+
+    /// Parses |token| into a |T|, or returns an error expecting |expected|.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Returns the next token from |tokens|, which are split from |string|.
+    pub fn next(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        string: &'a str,
+        expected: &str,
+    ) -> Result<&'a str, ParseError> {
+        tokens.next().ok_or_else(|| self.missing(string, expected))
+    }
+
+    /// Parses the next token from |tokens|, which are split from |string|, into a |T|.
+    pub fn parse_next<T: FromStr>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        string: &'a str,
+        expected: &str,
+    ) -> Result<T, ParseError> {
+        self.parse(self.next(tokens, string, expected)?, expected)
+    }
+}
+
+/// Returns the value of |result|, or prints the error and exits the process.
+pub fn or_exit<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    })
+}
+
+#[test]
+fn tests() {
+    let input = "12 34\nab é cd\n";
+    let source = Source::new(1, input);
+
+    let error = source.error(&input[3..5], "a letter");
+    assert_eq!((error.line, error.column), (1, 4));
+    assert_eq!(error.found, "34");
+
+    let error = source.error(&input[12..14], "a number");
+    assert_eq!((error.line, error.column), (2, 6));
+    assert_eq!(
+        error.to_string(),
+        "Day 1, line 2, column 6: expected a number, found \"cd\""
+    );
+
+    let error = source.char_error(&input[6..], 3, "a letter");
+    assert_eq!((error.line, error.column), (2, 4));
+    assert_eq!(error.found, "é");
+
+    let error = source.missing(&input[..5], "a third number");
+    assert_eq!((error.line, error.column), (1, 6));
+    assert_eq!(
+        error.to_string(),
+        "Day 1, line 1, column 6: expected a third number, found nothing"
+    );
+
+    assert_eq!(source.split_once(&input[..5], " "), Ok(("12", "34")));
+    let error = source.split_once(&input[..5], ",").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Day 1, line 1, column 6: expected \",\", found nothing"
+    );
+
+    let mut tokens = input[..5].split(' ');
+    assert_eq!(
+        source.parse_next::<u32>(&mut tokens, &input[..5], "a number"),
+        Ok(12)
+    );
+    assert_eq!(
+        source.parse_next::<u32>(&mut tokens, &input[..5], "a number"),
+        Ok(34)
+    );
+    assert!(source
+        .parse_next::<u32>(&mut tokens, &input[..5], "a number")
+        .is_err());
+}
@@ -0,0 +1,57 @@
+//! Recording the states of the simulations as frames, to be watched or saved as images.
+//!
+//! Simulations record into the recorder installed with |set_recorder|. Until one is installed,
+//! the frames are not even drawn.
+
+use std::{io, sync::OnceLock};
+
+use crate::grid::Grid;
+
+/// The colour of every kind of cell of a frame, as red, green and blue. The first one is the
+/// background.
+pub type Palette = &'static [(char, [u8; 3])];
+
+/// The colour of the cells missing from the palette of their frame.
+pub const UNKNOWN: [u8; 3] = [255, 0, 255];
+
+pub trait Recorder: Send + Sync {
+    /// Called with every frame of |task|, whose cells are coloured by |palette|. The frame is only
+    /// drawn by calling |draw|, so that the frames that are not wanted cost nothing.
+    fn frame(&self, task: &str, palette: Palette, draw: &mut dyn FnMut() -> Grid<char>);
+
+    /// Called once every simulation is over, to save whatever is left.
+    fn finish(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+static RECORDER: OnceLock<Box<dyn Recorder>> = OnceLock::new();
+
+/// Installs the recorder of every simulation. Only the first recorder installed is kept.
+pub fn set_recorder(recorder: Box<dyn Recorder>) {
+    let _ = RECORDER.set(recorder);
+}
+
+/// Returns whether the frames are recorded, for simulations that need to prepare to draw them.
+pub fn is_recording() -> bool {
+    RECORDER.get().is_some()
+}
+
+/// Records the next frame of |task|, drawn by |draw| if it is wanted.
+pub fn record(task: &str, palette: Palette, mut draw: impl FnMut() -> Grid<char>) {
+    if let Some(recorder) = RECORDER.get() {
+        recorder.frame(task, palette, &mut draw);
+    }
+}
+
+pub fn finish() -> io::Result<()> {
+    RECORDER.get().map_or(Ok(()), |recorder| recorder.finish())
+}
+
+/// Returns the colour of |cell| in |palette|.
+pub fn colour(palette: Palette, cell: char) -> [u8; 3] {
+    palette
+        .iter()
+        .find(|(other, _)| *other == cell)
+        .map_or(UNKNOWN, |(_, colour)| *colour)
+}
@@ -0,0 +1,256 @@
+//! A rectangular grid of cells, as found in many puzzle inputs.
+
+use std::ops::{Index, IndexMut};
+
+use crate::error::{ParseError, Source};
+
+/// The position of a cell, as its column and its row.
+pub type Point = (usize, usize);
+
+/// The offsets to the four orthogonal neighbours of a cell.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets to the eight neighbours of a cell, diagonals included.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells, row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses |string| with one row per line and one cell per character. |cell| returns None for
+    /// characters that are not cells, which are reported as errors expecting |expected|.
+    pub fn parse<'a>(
+        source: &Source<'a>,
+        string: &'a str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let string = string.trim();
+        if string.is_empty() {
+            return Err(source.missing(string, "a grid"));
+        }
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for line in string.split('\n') {
+            let line = line.trim_end();
+            for (index, character) in line.char_indices() {
+                let cell =
+                    cell(character).ok_or_else(|| source.char_error(line, index, expected))?;
+                grid.cells.push(cell);
+            }
+            if grid.height == 0 {
+                grid.width = grid.cells.len();
+            } else if grid.cells.len() != (grid.height + 1) * grid.width {
+                return Err(source.error(line, format!("a row of {} cells", grid.width)));
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.0 < self.width && point.1 < self.height
+    }
+
+    /// Returns the cell at |point|, or None if it is outside of the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at |point|, or None if it is outside of the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Returns the first point, row by row, whose cell matches |predicate|.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Returns |point| moved by |offset|, or None if that leaves the grid.
+    pub fn step(&self, point: Point, offset: (isize, isize)) -> Option<Point> {
+        let x = point.0.checked_add_signed(offset.0)?;
+        let y = point.1.checked_add_signed(offset.1)?;
+        Some((x, y)).filter(|point| self.contains(*point))
+    }
+
+    /// Returns the orthogonal neighbours of |point| inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |offset| self.step(point, *offset))
+    }
+
+    /// Returns the orthogonal and diagonal neighbours of |point| inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |offset| self.step(point, *offset))
+    }
+
+    /// Returns a grid of the same size, with |function| applied to every cell.
+    pub fn map<U>(&self, function: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(function).collect(),
+        }
+    }
+
+    /// Adds or removes rows at the end of the grid, filling new cells with |value|.
+    pub fn resize_height(&mut self, height: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.resize(height * self.width, value);
+        self.height = height;
+    }
+
+    /// Returns the grid mirrored along its diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Returns the grid as text, one line per row, using |cell| to draw each cell.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        (0..self.height)
+            .map(|y| self.row(y).iter().map(&cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+#[test]
+fn tests() {
+    let input = "#..\n.#.\n";
+    let source = Source::new(1, input);
+    let grid = Grid::parse(&source, input, "# or .", |cell| match cell {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert!(grid[(1, 1)]);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.find(|cell| *cell), Some((0, 0)));
+    assert_eq!(
+        grid.render(|cell| if *cell { '#' } else { '.' }),
+        "#..\n.#."
+    );
+
+    let neighbours: Vec<Point> = grid.neighbours4((0, 0)).collect();
+    assert_eq!(neighbours, vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+
+    assert_eq!(grid.map(|cell| *cell as u8).row(1), &[0, 1, 0]);
+
+    let transposed = grid.transpose();
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(
+        transposed.render(|cell| if *cell { '#' } else { '.' }),
+        "#.\n.#\n.."
+    );
+
+    let input = "#..\n.x.\n#.";
+    let source = Source::new(1, input);
+    let error = Grid::parse(&source, input, "# or .", |cell| {
+        (cell != 'x').then_some(cell)
+    });
+    assert_eq!(
+        error.unwrap_err().to_string(),
+        "Day 1, line 2, column 2: expected # or ., found \"x\""
+    );
+    let input = "#..\n#.";
+    let source = Source::new(1, input);
+    let error = Grid::parse(&source, input, "# or .", Some).unwrap_err();
+    assert_eq!(error.expected, "a row of 3 cells");
+}
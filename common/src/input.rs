@@ -0,0 +1,43 @@
+//! Reading puzzle inputs.
+
+use std::{
+    env, fs,
+    io::{self, Read},
+    process,
+};
+
+/// The path that stands for the standard input.
+pub const STDIN: &str = "-";
+
+/// The path used when none is given.
+pub const DEFAULT_PATH: &str = "input.txt";
+
+/// Reads the puzzle input at |path|, or the standard input if |path| is `-`. Windows line endings
+/// are converted so that every solver can split on `\n`.
+pub fn read(path: &str) -> io::Result<String> {
+    let input = if path == STDIN {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(input.replace("\r\n", "\n"))
+}
+
+/// Reads the puzzle input at |path|, exiting the process if it cannot be read.
+pub fn read_or_exit(path: &str) -> String {
+    read(path).unwrap_or_else(|error| {
+        eprintln!("Could not read {path}: {error}");
+        process::exit(1);
+    })
+}
+
+/// Reads the puzzle input at the path given as the first command line argument, or `input.txt` if
+/// there is none. Exits the process if the input cannot be read.
+pub fn from_args() -> String {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from(DEFAULT_PATH));
+    read_or_exit(&path)
+}
@@ -0,0 +1,200 @@
+//! Sets of integers stored as the ranges they cover.
+
+use std::ops::Range;
+
+/// A set of integers, kept as sorted ranges that neither overlap nor touch each other, so that
+/// every set has exactly one representation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Returns the ranges covered by the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// Returns the number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.start.abs_diff(range.end))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds every integer of |range| to the set, merging it with the ranges it touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes every integer of |range| from the set, splitting the ranges it falls within.
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        let mut remains = Vec::new();
+        if first < last {
+            let start = self.ranges[first].start;
+            let end = self.ranges[last - 1].end;
+            if start < range.start {
+                remains.push(start..range.start);
+            }
+            if range.end < end {
+                remains.push(range.end..end);
+            }
+        }
+        self.ranges.splice(first..last, remains);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.covers(&(value..value + 1))
+    }
+
+    /// Returns whether every integer of |range| is in the set.
+    pub fn covers(&self, range: &Range<i64>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|other| other.start <= range.start && range.end <= other.end)
+    }
+
+    /// Returns whether any integer of |range| is in the set.
+    pub fn overlaps(&self, range: &Range<i64>) -> bool {
+        let index = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        !range.is_empty()
+            && self
+                .ranges
+                .get(index)
+                .is_some_and(|other| other.start < range.end)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(range.clone());
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut mine, mut theirs) = (0, 0);
+        while mine < self.ranges.len() && theirs < other.ranges.len() {
+            let (first, second) = (&self.ranges[mine], &other.ranges[theirs]);
+            let start = first.start.max(second.start);
+            let end = first.end.min(second.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if first.end < second.end {
+                mine += 1;
+            } else {
+                theirs += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Returns the integers of the set that are not in |other|.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.remove(range.clone());
+        }
+        result
+    }
+
+    /// Returns the ranges of |within| that are not covered by the set, in increasing order.
+    pub fn gaps(&self, within: Range<i64>) -> impl Iterator<Item = Range<i64>> {
+        IntervalSet::from(within)
+            .difference(self)
+            .ranges
+            .into_iter()
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[test]
+fn tests() {
+    // Overlapping and touching ranges are merged, empty ones are ignored.
+    let set: IntervalSet = [5..8, 0..2, 7..10, 2..3, 20..20, 12..15]
+        .into_iter()
+        .collect();
+    assert_eq!(set.ranges(), &[0..3, 5..10, 12..15]);
+    assert_eq!(set.len(), 11);
+    assert!(set.contains(0) && set.contains(9) && !set.contains(3) && !set.contains(15));
+    assert!(set.covers(&(5..10)) && !set.covers(&(4..6)) && set.covers(&(4..4)));
+    assert!(set.overlaps(&(9..12)) && !set.overlaps(&(10..12)) && !set.overlaps(&(6..6)));
+
+    let mut removed = set.clone();
+    removed.remove(6..13);
+    assert_eq!(removed.ranges(), &[0..3, 5..6, 13..15]);
+    removed.remove(-5..0);
+    assert_eq!(removed.len(), 6);
+
+    let other: IntervalSet = [1..6, 9..13].into_iter().collect();
+    assert_eq!(set.union(&other), IntervalSet::from(0..15));
+    assert_eq!(
+        set.intersection(&other).ranges(),
+        &[1..3, 5..6, 9..10, 12..13]
+    );
+    assert_eq!(set.difference(&other).ranges(), &[0..1, 6..9, 13..15]);
+    assert_eq!(
+        set.intersection(&other),
+        set.difference(&set.difference(&other))
+    );
+
+    let gaps: Vec<Range<i64>> = set.gaps(-1..20).collect();
+    assert_eq!(gaps, vec![-1..0, 3..5, 10..12, 15..20]);
+    assert_eq!(set.gaps(5..10).count(), 0);
+    assert!(IntervalSet::new().is_empty());
+}
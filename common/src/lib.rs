@@ -0,0 +1,11 @@
+//! Code shared between the days.
+
+pub mod cycle;
+pub mod error;
+pub mod frame;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod pattern;
+pub mod progress;
+pub mod search;
@@ -0,0 +1,197 @@
+//! Parsing lines against patterns of fixed text and fields, such as
+//! `move {a number of crates} from {a stack} to {a stack}`.
+//!
+//! Every field of a pattern is named by what it should hold, which is what the errors say was
+//! expected when the field is missing or cannot be parsed.
+
+use std::{str::FromStr, vec};
+
+use crate::error::{ParseError, Source};
+
+/// The grammar of a line, as fixed text with a field in every pair of braces. A field ends where
+/// the text following it is first found, or with the line if it is the last one.
+#[derive(Clone, Copy)]
+pub struct Pattern {
+    template: &'static str,
+}
+
+/// The fields matched by a |Pattern|, in order.
+pub struct Fields<'a> {
+    source: Source<'a>,
+    fields: vec::IntoIter<(&'a str, &'static str)>,
+}
+
+/// Returns |string| without |text|, which must start it.
+fn literal<'a>(source: &Source<'a>, string: &'a str, text: &str) -> Result<&'a str, ParseError> {
+    string.strip_prefix(text).ok_or_else(|| {
+        // Point at as much of |string| as |text| would have covered.
+        let end = string
+            .char_indices()
+            .map(|(index, char)| index + char.len_utf8())
+            .take_while(|end| *end <= text.len())
+            .last()
+            .unwrap_or(0);
+        source.error(&string[..end], format!("{text:?}"))
+    })
+}
+
+impl Pattern {
+    pub const fn new(template: &'static str) -> Pattern {
+        Pattern { template }
+    }
+
+    /// Matches |line|, which must be a slice of the input of |source|, against the pattern.
+    pub fn parse<'a>(&self, source: &Source<'a>, line: &'a str) -> Result<Fields<'a>, ParseError> {
+        let mut fields = Vec::new();
+        let mut rest = line;
+        let mut template = self.template;
+        loop {
+            let (text, field) = match template.split_once('{') {
+                Some((text, field)) => (text, Some(field)),
+                None => (template, None),
+            };
+            rest = literal(source, rest, text)?;
+            let Some(field) = field else {
+                break;
+            };
+            let (expected, after) = field
+                .split_once('}')
+                .expect("Every field of the pattern must be closed");
+            template = after;
+            let next = template.split('{').next().unwrap();
+            let end = if next.is_empty() {
+                rest.len()
+            } else {
+                rest.find(next)
+                    .ok_or_else(|| source.missing(rest, format!("{next:?}")))?
+            };
+            if end == 0 {
+                return Err(source.error(&rest[..0], expected));
+            }
+            fields.push((&rest[..end], expected));
+            rest = &rest[end..];
+        }
+        if !rest.is_empty() {
+            return Err(source.error(rest, "the end of the line"));
+        }
+        Ok(Fields {
+            source: *source,
+            fields: fields.into_iter(),
+        })
+    }
+
+    /// Matches |line| against the first of |patterns| it fits, returning the index of that pattern
+    /// along with its fields. If none fits, returns the error of the one that fit the furthest.
+    pub fn parse_any<'a>(
+        patterns: &[Pattern],
+        source: &Source<'a>,
+        line: &'a str,
+    ) -> Result<(usize, Fields<'a>), ParseError> {
+        let mut furthest: Option<ParseError> = None;
+        for (index, pattern) in patterns.iter().enumerate() {
+            match pattern.parse(source, line) {
+                Ok(fields) => return Ok((index, fields)),
+                Err(error) => {
+                    if furthest.as_ref().is_none_or(|furthest| {
+                        (error.line, error.column) > (furthest.line, furthest.column)
+                    }) {
+                        furthest = Some(error);
+                    }
+                }
+            }
+        }
+        Err(furthest.expect("There must be at least one pattern"))
+    }
+}
+
+impl<'a> Fields<'a> {
+    /// Returns the next field along with what it should hold. The pattern decides how many there
+    /// are, so asking for more is a bug of the caller rather than of the input.
+    fn next_field(&mut self) -> (&'a str, &'static str) {
+        self.fields.next().expect("The pattern has no more fields")
+    }
+
+    /// Returns the text of the next field.
+    pub fn text(&mut self) -> &'a str {
+        self.next_field().0
+    }
+
+    /// Parses the next field into a |T|.
+    pub fn parse<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let (field, expected) = self.next_field();
+        self.source.parse(field, expected)
+    }
+
+    /// Parses every item of the next field, which are separated by |separator|, into a |T|.
+    pub fn parse_list<T: FromStr>(&mut self, separator: &str) -> Result<Vec<T>, ParseError> {
+        let (field, expected) = self.next_field();
+        field
+            .split(separator)
+            .map(|item| self.source.parse(item, expected))
+            .collect()
+    }
+}
+
+#[test]
+fn tests() {
+    let input = "move 3 from 1 to 2\nmove x from 1\nitems: 1, 2, 3\n";
+    let source = Source::new(1, input);
+    let lines: Vec<&str> = input.split('\n').collect();
+    let instruction = Pattern::new("move {a count} from {a stack} to {a stack}");
+
+    let mut fields = instruction.parse(&source, lines[0]).unwrap();
+    assert_eq!(fields.parse::<u32>(), Ok(3));
+    assert_eq!(fields.text(), "1");
+    assert_eq!(fields.parse::<u32>(), Ok(2));
+
+    let mut fields = Pattern::new("move {a count} from {a stack}")
+        .parse(&source, lines[1])
+        .unwrap();
+    assert_eq!(
+        fields.parse::<u32>().unwrap_err().to_string(),
+        "Day 1, line 2, column 6: expected a count, found \"x\""
+    );
+    assert_eq!(
+        instruction
+            .parse(&source, lines[1])
+            .err()
+            .unwrap()
+            .to_string(),
+        "Day 1, line 2, column 14: expected \" to \", found nothing"
+    );
+    assert_eq!(
+        instruction
+            .parse(&source, lines[2])
+            .err()
+            .unwrap()
+            .to_string(),
+        "Day 1, line 3, column 1: expected \"move \", found \"items\""
+    );
+    assert_eq!(
+        Pattern::new("move {a count}")
+            .parse(&source, &lines[0][..5])
+            .err()
+            .unwrap()
+            .to_string(),
+        "Day 1, line 1, column 6: expected a count, found nothing"
+    );
+    assert_eq!(
+        Pattern::new("move {a count} from")
+            .parse(&source, lines[1])
+            .err()
+            .unwrap()
+            .to_string(),
+        "Day 1, line 2, column 12: expected the end of the line, found \" 1\""
+    );
+
+    let items = Pattern::new("items: {an item}");
+    let mut fields = items.parse(&source, lines[2]).unwrap();
+    assert_eq!(fields.parse_list::<u32>(", "), Ok(vec![1, 2, 3]));
+
+    let (index, _) = Pattern::parse_any(&[instruction, items], &source, lines[2]).unwrap();
+    assert_eq!(index, 1);
+    let error = Pattern::parse_any(&[items, instruction], &source, lines[1])
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (2, 14));
+}
@@ -0,0 +1,97 @@
+//! Reporting the progress and the diagnostics of the solvers, away from their answers.
+//!
+//! Solvers report into the observer installed with |set_observer|. Until one is installed,
+//! everything they report is dropped.
+
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::OnceLock,
+};
+
+pub trait Observer: Send + Sync {
+    /// Called when |done| out of |total| steps of |task| are finished.
+    fn progress(&self, task: &str, done: usize, total: usize);
+
+    /// Called with details that help to follow what a solver does.
+    fn message(&self, message: fmt::Arguments);
+}
+
+static OBSERVER: OnceLock<Box<dyn Observer>> = OnceLock::new();
+
+/// Installs the observer of every solver. Only the first observer installed is kept.
+pub fn set_observer(observer: Box<dyn Observer>) {
+    let _ = OBSERVER.set(observer);
+}
+
+pub fn progress(task: &str, done: usize, total: usize) {
+    if let Some(observer) = OBSERVER.get() {
+        observer.progress(task, done, total);
+    }
+}
+
+pub fn message(message: fmt::Arguments) {
+    if let Some(observer) = OBSERVER.get() {
+        observer.message(message);
+    }
+}
+
+/// Reports a message to the observer, formatted like |format!|.
+#[macro_export]
+macro_rules! message {
+    ($($arg:tt)*) => {
+        $crate::progress::message(format_args!($($arg)*))
+    };
+}
+
+/// Returns whether |done| steps out of |total| reach a new one of |steps| equal parts of the
+/// task, so that long tasks are not reported at every single step.
+fn is_milestone(done: usize, total: usize, steps: usize) -> bool {
+    done == total || done == 1 || done * steps / total.max(1) != (done - 1) * steps / total.max(1)
+}
+
+/// Writes the progress and the messages to the standard error, one line each.
+pub struct Verbose;
+
+impl Observer for Verbose {
+    fn progress(&self, task: &str, done: usize, total: usize) {
+        if is_milestone(done, total, 20) {
+            eprintln!("{task}: {done} of {total}");
+        }
+    }
+
+    fn message(&self, message: fmt::Arguments) {
+        eprintln!("{message}");
+    }
+}
+
+/// Draws a bar on the standard error for every task, and drops the messages.
+pub struct ProgressBar;
+
+const BAR_WIDTH: usize = 40;
+
+impl Observer for ProgressBar {
+    fn progress(&self, task: &str, done: usize, total: usize) {
+        if !is_milestone(done, total, BAR_WIDTH) {
+            return;
+        }
+        let filled = done * BAR_WIDTH / total.max(1);
+        let bar = "#".repeat(filled) + &".".repeat(BAR_WIDTH - filled);
+        let end = if done == total { "\n" } else { "" };
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r{task} [{bar}] {done}/{total}{end}");
+        let _ = stderr.flush();
+    }
+
+    fn message(&self, _: fmt::Arguments) {}
+}
+
+#[test]
+fn tests() {
+    let milestones: Vec<usize> = (1..=100)
+        .filter(|done| is_milestone(*done, 100, 4))
+        .collect();
+    assert_eq!(milestones, vec![1, 25, 50, 75, 100]);
+    assert!(is_milestone(1, 1, 20));
+    assert_eq!((1..=3).filter(|done| is_milestone(*done, 3, 40)).count(), 3);
+}
@@ -0,0 +1,204 @@
+//! Shortest path searches over graphs given by a successor function.
+//!
+//! Every search returns the cost of the path it found along with the path itself, from the start
+//! node to the goal node, both included.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The nodes visited by a search, each pointing back at the node it was reached from.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    costs: Vec<usize>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Visited<N> {
+        Visited {
+            nodes: vec![start.clone()],
+            parents: vec![0],
+            costs: vec![0],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Records that |node| can be reached from |parent| with a total of |cost|. Returns the index
+    /// of |node| if it was not visited yet or if this is a cheaper way to reach it.
+    fn visit(&mut self, node: N, parent: usize, cost: usize) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.parents.push(parent);
+                self.costs.push(cost);
+                entry.insert(index);
+                Some(index)
+            }
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost < self.costs[index] {
+                    self.parents[index] = parent;
+                    self.costs[index] = cost;
+                    Some(index)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the cost of reaching the node at |index| along with the path leading to it.
+    fn path(&self, mut index: usize) -> (usize, Vec<N>) {
+        let cost = self.costs[index];
+        let mut path = vec![self.nodes[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        (cost, path)
+    }
+}
+
+/// Finds the path with the fewest steps from |start| to a node matching |is_goal|.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        let cost = visited.costs[index] + 1;
+        for next in successors(&node) {
+            if let Some(next) = visited.visit(next, index, cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest path from |start| to a node matching |is_goal|. |successors| returns the
+/// neighbours of a node along with the cost of moving to them.
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// Finds the cheapest path from |start| to a node matching |is_goal|, exploring the nodes that
+/// |heuristic| estimates closest to the goal first. The heuristic must never overestimate.
+pub fn astar<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut fringe = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+    while let Some(Reverse((_, cost, index))) = fringe.pop() {
+        if cost > visited.costs[index] {
+            // A cheaper way to this node was found after this one was queued.
+            continue;
+        }
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if let Some(next) = visited.visit(next, index, next_cost) {
+                let estimate = next_cost + heuristic(&visited.nodes[next]);
+                fringe.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the fewest steps from |start| to every node reachable from it.
+pub fn distances<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let node = visited.nodes[index].clone();
+        let cost = visited.costs[index] + 1;
+        for next in successors(&node) {
+            if let Some(next) = visited.visit(next, index, cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited
+        .indices
+        .into_iter()
+        .map(|(node, index)| (node, visited.costs[index]))
+        .collect()
+}
+
+#[test]
+fn tests() {
+    // A line of numbers, where each number leads to the next and to its double.
+    let successors = |number: &u32| {
+        [number + 1, number * 2]
+            .into_iter()
+            .filter(|next| *next <= 20)
+    };
+    assert_eq!(
+        bfs(1, successors, |number| *number == 20),
+        Some((5, vec![1, 2, 4, 5, 10, 20]))
+    );
+    assert_eq!(bfs(1, successors, |number| *number == 21), None);
+    assert_eq!(distances(1, successors)[&20], 5);
+    assert_eq!(distances(1, successors).len(), 20);
+
+    // Doubling costs as much as the number it lands on.
+    let weighted = |number: &u32| {
+        let number = *number;
+        successors(&number)
+            .map(move |next| (next, if next == number + 1 { 1 } else { next as usize }))
+    };
+    assert_eq!(
+        dijkstra(1, weighted, |number| *number == 20),
+        Some((19, (1..=20).collect()))
+    );
+    let (cost, path) = dijkstra(1, weighted, |number| *number == 10).unwrap();
+    assert_eq!((cost, path.len()), (9, 10));
+    let heuristic = |number: &u32| 20 - *number as usize;
+    assert_eq!(
+        astar(1, weighted, heuristic, |number| *number == 20),
+        dijkstra(1, weighted, |number| *number == 20)
+    );
+    assert_eq!(
+        astar(7, weighted, heuristic, |number| *number == 7),
+        Some((0, vec![7]))
+    );
+}
@@ -0,0 +1,231 @@
+//! The calories carried by every elf, item by item, with queries and reports over their totals.
+
+use std::{cmp::Ordering, fmt::Write};
+
+use common::error::{ParseError, Source};
+
+use super::DAY;
+
+/// The items carried by an elf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    /// The position of the elf in the input, starting at 1.
+    pub number: usize,
+    /// The calories of every item, in the order of the input.
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().map(|calories| *calories as u64).sum()
+    }
+}
+
+/// Orders the elves carrying the most first, and the elves carrying as much by their number.
+fn by_total(a: &Elf, b: &Elf) -> Ordering {
+    b.total().cmp(&a.total()).then(a.number.cmp(&b.number))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ledger {
+    elves: Vec<Elf>,
+}
+
+impl Ledger {
+    /// Reads the calories of every item, one per line, with blank lines between elves.
+    pub fn from(input: &str) -> Result<Ledger, ParseError> {
+        let source = Source::new(DAY, input);
+        let mut elves = Vec::new();
+        let mut items = Vec::new();
+        // An elf ends with the first blank line after its items, and the input with the last one.
+        for line in input.lines().chain([""]) {
+            let line = line.trim();
+            if !line.is_empty() {
+                items.push(source.parse(line, "a number of calories")?);
+            } else if !items.is_empty() {
+                elves.push(Elf {
+                    number: elves.len() + 1,
+                    items: std::mem::take(&mut items),
+                });
+            }
+        }
+        Ok(Ledger { elves })
+    }
+
+    /// Returns every elf, in the order of the input.
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// Returns the |k| elves carrying the most, from the one carrying the most. Elves carrying as
+    /// much come in the order of the input.
+    pub fn top(&self, k: usize) -> Vec<&Elf> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut elves: Vec<&Elf> = self.elves.iter().collect();
+        // Only the |k| first elves are sorted, once they are set apart from the others.
+        if k < elves.len() {
+            elves.select_nth_unstable_by(k - 1, |a, b| by_total(a, b));
+            elves.truncate(k);
+        }
+        elves.sort_unstable_by(|a, b| by_total(a, b));
+        elves
+    }
+
+    /// Returns the calories carried by the |k| elves carrying the most.
+    pub fn top_total(&self, k: usize) -> u64 {
+        self.top(k).iter().map(|elf| elf.total()).sum()
+    }
+
+    /// Returns the total carried by the elf at |rank| of the elves sorted from the one carrying
+    /// the least, starting at 0.
+    fn nth_smallest(&self, rank: usize) -> u64 {
+        let mut totals: Vec<u64> = self.elves.iter().map(Elf::total).collect();
+        *totals.select_nth_unstable(rank).1
+    }
+
+    /// Returns the median of the calories carried by every elf, halfway between the two middle
+    /// elves if there is an even number of them.
+    pub fn median(&self) -> Option<f64> {
+        let count = self.elves.len();
+        if count == 0 {
+            return None;
+        }
+        let upper = self.nth_smallest(count / 2) as f64;
+        if count % 2 == 1 {
+            Some(upper)
+        } else {
+            Some((self.nth_smallest(count / 2 - 1) as f64 + upper) / 2.0)
+        }
+    }
+
+    /// Returns the least calories carried by at least |percent|% of the elves carrying the least,
+    /// by the nearest rank. There is none without elves or outside of 0 to 100%.
+    pub fn percentile(&self, percent: f64) -> Option<u64> {
+        if self.elves.is_empty() || !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+        let rank = (percent / 100.0 * self.elves.len() as f64).ceil() as usize;
+        Some(self.nth_smallest(rank.max(1) - 1))
+    }
+
+    /// Returns the rank of every elf, starting at 1 for the one carrying the most, by number.
+    fn ranks(&self) -> Vec<usize> {
+        let mut ranks = vec![0; self.elves.len()];
+        for (rank, elf) in self.top(self.elves.len()).into_iter().enumerate() {
+            ranks[elf.number - 1] = rank + 1;
+        }
+        ranks
+    }
+
+    /// Returns a line per elf with its number, rank, number of items, total and the calories of
+    /// every item separated by semicolons, below a header.
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("elf,rank,items,total,calories\n");
+        for (elf, rank) in self.elves.iter().zip(self.ranks()) {
+            let calories: Vec<String> = elf.items.iter().map(u32::to_string).collect();
+            let _ = writeln!(
+                csv,
+                "{},{rank},{},{},{}",
+                elf.number,
+                elf.items.len(),
+                elf.total(),
+                calories.join(";")
+            );
+        }
+        csv
+    }
+
+    /// Returns the same columns as |csv|, aligned for reading.
+    pub fn table(&self) -> String {
+        let rows: Vec<[String; 5]> = self
+            .elves
+            .iter()
+            .zip(self.ranks())
+            .map(|(elf, rank)| {
+                let calories: Vec<String> = elf.items.iter().map(u32::to_string).collect();
+                [
+                    elf.number.to_string(),
+                    rank.to_string(),
+                    elf.items.len().to_string(),
+                    elf.total().to_string(),
+                    calories.join(" "),
+                ]
+            })
+            .collect();
+        let header = ["Elf", "Rank", "Items", "Total", "Calories"].map(String::from);
+        let mut widths = [0; 5];
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        let mut table = String::new();
+        for row in std::iter::once(&header).chain(&rows) {
+            // The numbers are aligned to the right, and the list of calories to the left.
+            let mut line = String::new();
+            for (column, (width, cell)) in widths.iter().zip(row).enumerate() {
+                if column + 1 == row.len() {
+                    let _ = write!(line, "{cell}");
+                } else {
+                    let _ = write!(line, "{cell:>width$}  ");
+                }
+            }
+            let _ = writeln!(table, "{}", line.trim_end());
+        }
+        table
+    }
+}
+
+#[test]
+fn tests() {
+    let ledger = Ledger::from(include_str!("../example.txt")).unwrap();
+    assert_eq!(5, ledger.elves().len());
+    assert_eq!(
+        Elf {
+            number: 2,
+            items: vec![4000]
+        },
+        ledger.elves()[1]
+    );
+    let top: Vec<usize> = ledger.top(3).iter().map(|elf| elf.number).collect();
+    assert_eq!(vec![4, 3, 5], top);
+    assert_eq!(45000, ledger.top_total(3));
+    assert_eq!(5, ledger.top(10).len());
+    assert!(ledger.top(0).is_empty());
+    assert_eq!(Some(10000.0), ledger.median());
+    assert_eq!(Some(4000), ledger.percentile(0.0));
+    assert_eq!(Some(6000), ledger.percentile(40.0));
+    assert_eq!(Some(24000), ledger.percentile(100.0));
+    assert_eq!(None, ledger.percentile(101.0));
+
+    let even = Ledger::from("1\n\n2\n3\n\n10\n\n4").unwrap();
+    assert_eq!(Some(4.5), even.median());
+    assert_eq!(None, Ledger::from("").unwrap().median());
+
+    // Several blank lines in a row still separate only two elves.
+    let spaced = Ledger::from("\n1\n\n\n2\n\n").unwrap();
+    let numbers: Vec<(usize, u64)> = spaced
+        .elves()
+        .iter()
+        .map(|elf| (elf.number, elf.total()))
+        .collect();
+    assert_eq!(vec![(1, 1), (2, 2)], numbers);
+
+    let csv = ledger.csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!("elf,rank,items,total,calories", lines[0]);
+    assert_eq!("1,4,3,6000,1000;2000;3000", lines[1]);
+    assert_eq!("4,1,3,24000,7000;8000;9000", lines[4]);
+
+    let table = ledger.table();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!("Elf  Rank  Items  Total  Calories", lines[0]);
+    assert_eq!("  1     4      3   6000  1000 2000 3000", lines[1]);
+
+    assert_eq!(
+        "Day 1, line 2, column 1: expected a number of calories, found \"x\"",
+        Ledger::from("1\nx").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,34 @@
+use common::error::ParseError;
+
+use stream::Aggregator;
+
+pub mod ledger;
+pub mod stream;
+
+const DAY: u8 = 1;
+
+/// Returns the calories carried by the |k| elves carrying the most.
+fn top_total(input: &str, k: usize) -> Result<u64, ParseError> {
+    let mut aggregator = Aggregator::new(k);
+    for line in input.lines() {
+        aggregator.push(line)?;
+    }
+    Ok(aggregator.finish().iter().sum())
+}
+
+/// Returns the calories carried by the elf carrying the most.
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    top_total(input, 1)
+}
+
+/// Returns the calories carried by the three elves carrying the most.
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    top_total(input, 3)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(24000, part1(example).unwrap());
+    assert_eq!(45000, part2(example).unwrap());
+}
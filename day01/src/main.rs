@@ -1,24 +1,65 @@
-use std::{fs, ops::AddAssign};
+//! Usage: `day01 [input] [--csv|--table]`, in any order. The input defaults to `input.txt`.
+//!
+//! The input is read line by line, so that it can be of any size. With `--csv` or `--table`, it is
+//! read whole instead, to report the calories carried by every elf after the answers.
 
-fn main() {
-    let file = fs::read_to_string("input.txt").unwrap();
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    process,
+};
+
+use common::{error::or_exit, input};
+use day01::{
+    ledger::Ledger,
+    stream::{Aggregator, StreamError},
+};
 
-    let mut elves: Vec<u32> = vec![0];
-    for line in file.trim().split('\n') {
-        let line = line.trim();
-        if line.is_empty() {
-            elves.push(0);
-        } else {
-            elves
-                .last_mut()
-                .unwrap()
-                .add_assign(line.parse::<u32>().unwrap());
+enum Report {
+    Csv,
+    Table,
+}
+
+fn main() {
+    let mut path = String::from(input::DEFAULT_PATH);
+    let mut report = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--csv" => report = Some(Report::Csv),
+            "--table" => report = Some(Report::Table),
+            _ if arg.starts_with("--") => {
+                eprintln!("Unknown argument {arg}");
+                process::exit(1);
+            }
+            _ => path = arg,
+        }
+    }
+    match report {
+        Some(report) => {
+            let file = input::read_or_exit(&path);
+            println!("Part 1: {}", or_exit(day01::part1(&file)));
+            println!("Part 2: {}", or_exit(day01::part2(&file)));
+            let ledger = or_exit(Ledger::from(&file));
+            match report {
+                Report::Csv => print!("\n{}", ledger.csv()),
+                Report::Table => print!("\n{}", ledger.table()),
+            }
+        }
+        None => {
+            let top = if path == input::STDIN {
+                Aggregator::read(io::stdin().lock(), 3)
+            } else {
+                File::open(&path)
+                    .map_err(StreamError::from)
+                    .and_then(|file| Aggregator::read(BufReader::new(file), 3))
+            };
+            let top = top.unwrap_or_else(|error| {
+                eprintln!("{path}: {error}");
+                process::exit(1);
+            });
+            println!("Part 1: {}", top.first().unwrap_or(&0));
+            println!("Part 2: {}", top.iter().sum::<u64>());
         }
     }
-    println!("{}", elves.iter().max().unwrap());
-
-    elves.sort_by(|a, b| b.partial_cmp(a).unwrap());
-    let mut iter = elves.iter();
-    let solution = iter.next().unwrap() + iter.next().unwrap() + iter.next().unwrap();
-    println!("{}", solution);
 }
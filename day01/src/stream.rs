@@ -0,0 +1,163 @@
+//! Totalling the calories of every elf line by line, keeping only the elves carrying the most, so
+//! that inputs of any size are read in constant memory.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+use common::error::ParseError;
+
+use super::DAY;
+
+/// An error met while reading a ledger.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Could not read the input: {error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> StreamError {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> StreamError {
+        StreamError::Parse(error)
+    }
+}
+
+/// Keeps the totals of the |k| elves carrying the most of the lines pushed so far.
+pub struct Aggregator {
+    k: usize,
+    /// The largest totals so far, with the smallest of them on top.
+    top: BinaryHeap<Reverse<u64>>,
+    /// The total of the elf being read, if any of its items were.
+    current: Option<u64>,
+    /// The number of lines pushed so far.
+    line: usize,
+}
+
+impl Aggregator {
+    pub fn new(k: usize) -> Aggregator {
+        Aggregator {
+            k,
+            top: BinaryHeap::with_capacity(k + 1),
+            current: None,
+            line: 0,
+        }
+    }
+
+    /// Adds the next line of the input, either the calories of an item or a blank line between
+    /// elves.
+    pub fn push(&mut self, line: &str) -> Result<(), ParseError> {
+        self.line += 1;
+        let line = line.trim_end_matches(['\n', '\r']);
+        let calories = line.trim();
+        if calories.is_empty() {
+            self.end_elf();
+            return Ok(());
+        }
+        let error = |expected: &str| ParseError {
+            day: DAY,
+            line: self.line,
+            column: line[..line.len() - line.trim_start().len()].chars().count() + 1,
+            expected: String::from(expected),
+            found: String::from(calories),
+        };
+        let calories: u32 = calories
+            .parse()
+            .map_err(|_| error("a number of calories"))?;
+        let total = self
+            .current
+            .unwrap_or(0)
+            .checked_add(calories as u64)
+            .ok_or_else(|| error("calories keeping the total within 64 bits"))?;
+        self.current = Some(total);
+        Ok(())
+    }
+
+    fn end_elf(&mut self) {
+        if let Some(total) = self.current.take() {
+            self.top.push(Reverse(total));
+            if self.top.len() > self.k {
+                self.top.pop();
+            }
+        }
+    }
+
+    /// Returns the totals of the |k| elves carrying the most, from the largest.
+    pub fn finish(mut self) -> Vec<u64> {
+        self.end_elf();
+        self.top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(total)| total)
+            .collect()
+    }
+
+    /// Returns the totals of the |k| elves carrying the most in the input read from |reader|, from
+    /// the largest.
+    pub fn read(mut reader: impl BufRead, k: usize) -> Result<Vec<u64>, StreamError> {
+        let mut aggregator = Aggregator::new(k);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            aggregator.push(&line)?;
+            line.clear();
+        }
+        Ok(aggregator.finish())
+    }
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(
+        vec![24000, 11000, 10000],
+        Aggregator::read(example.as_bytes(), 3).unwrap()
+    );
+    assert_eq!(
+        vec![24000, 11000, 10000, 6000, 4000],
+        Aggregator::read(example.as_bytes(), 10).unwrap()
+    );
+    assert!(Aggregator::read("".as_bytes(), 3).unwrap().is_empty());
+    assert!(Aggregator::read(example.as_bytes(), 0).unwrap().is_empty());
+
+    // Totals past 32 bits are kept whole.
+    let large = "4000000000\n4000000000\n\n1\n";
+    assert_eq!(
+        vec![8000000000],
+        Aggregator::read(large.as_bytes(), 1).unwrap()
+    );
+
+    let mut aggregator = Aggregator::new(1);
+    aggregator.push("1").unwrap();
+    aggregator.current = Some(u64::MAX - 1);
+    assert_eq!(
+        "Day 1, line 2, column 1: expected calories keeping the total within 64 bits, found \"2\"",
+        aggregator.push("2").unwrap_err().to_string()
+    );
+
+    assert_eq!(
+        "Day 1, line 3, column 3: expected a number of calories, found \"x\"",
+        Aggregator::read("1\n\n  x\n".as_bytes(), 3)
+            .unwrap_err()
+            .to_string()
+    );
+}
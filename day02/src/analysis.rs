@@ -0,0 +1,183 @@
+//! Explaining the score of a strategy guide round by round, against the best score that could be
+//! reached, and under every other way of decoding it.
+
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display},
+};
+
+use super::{Game, Outcome, Shape, Strategy};
+
+/// A round played by following the guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: Shape,
+    pub ours: Shape,
+    pub outcome: Outcome,
+    /// The score of the shape we played.
+    pub shape_score: u32,
+    /// The shape that would have scored the most.
+    pub best: Shape,
+    pub best_score: u32,
+}
+
+impl Round {
+    pub fn score(&self) -> u32 {
+        self.shape_score + self.outcome.score()
+    }
+
+    /// Returns whether the guide reached the best score of the round.
+    pub fn is_best(&self) -> bool {
+        self.score() == self.best_score
+    }
+}
+
+/// The rounds of a strategy guide, decoded with a strategy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Analysis {
+    pub rounds: Vec<Round>,
+}
+
+impl Analysis {
+    /// Plays |game| against the opponent's shapes of |rounds|, decoding our column with |strategy|.
+    pub fn new(game: &Game, rounds: &[(Shape, usize)], strategy: Strategy) -> Analysis {
+        let rounds = rounds
+            .iter()
+            .map(|(theirs, column)| {
+                let ours = strategy.shape(game, *theirs, *column);
+                let best = game.best(*theirs);
+                Round {
+                    theirs: *theirs,
+                    ours,
+                    outcome: game.outcome(ours, *theirs),
+                    shape_score: game.shape_score(ours),
+                    best,
+                    best_score: game.score(best, *theirs),
+                }
+            })
+            .collect();
+        Analysis { rounds }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.rounds.iter().map(Round::score).sum()
+    }
+
+    /// Returns the total of playing the best shape in every round.
+    pub fn best_total(&self) -> u32 {
+        self.rounds.iter().map(|round| round.best_score).sum()
+    }
+
+    /// Returns the rounds where the guide falls short of the best score, numbered from 1.
+    pub fn deviations(&self) -> impl Iterator<Item = (usize, &Round)> {
+        self.rounds
+            .iter()
+            .enumerate()
+            .filter(|(_, round)| !round.is_best())
+            .map(|(index, round)| (index + 1, round))
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, round) in self.rounds.iter().enumerate() {
+            write!(
+                f,
+                "Round {}: {:?} against {:?}, {:?}: {} + {} = {}",
+                index + 1,
+                round.ours,
+                round.theirs,
+                round.outcome,
+                round.shape_score,
+                round.outcome.score(),
+                round.score()
+            )?;
+            if round.is_best() {
+                writeln!(f)?;
+            } else {
+                writeln!(f, ", {:?} would score {}", round.best, round.best_score)?;
+            }
+        }
+        writeln!(
+            f,
+            "Total: {}, at best {}, with {} rounds short of the best",
+            self.total(),
+            self.best_total(),
+            self.deviations().count()
+        )
+    }
+}
+
+/// Returns every order of |items|.
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+/// Returns every way of decoding X, Y and Z as three different shapes of |game| or as the three
+/// outcomes.
+pub fn strategies(game: &Game) -> Vec<Strategy> {
+    let shapes = game.shapes();
+    let mut strategies = Vec::new();
+    for a in 0..shapes.len() {
+        for b in a + 1..shapes.len() {
+            for c in b + 1..shapes.len() {
+                let table = [shapes[a], shapes[b], shapes[c]];
+                strategies.extend(permutations(table).map(Strategy::Shapes));
+            }
+        }
+    }
+    strategies
+        .extend(permutations([Outcome::Loss, Outcome::Draw, Outcome::Win]).map(Strategy::Outcomes));
+    strategies
+}
+
+/// Returns the total of |rounds| under every decoding given by |strategies|, from the highest.
+pub fn distribution(game: &Game, rounds: &[(Shape, usize)]) -> Vec<(Strategy, u32)> {
+    let mut totals: Vec<(Strategy, u32)> = strategies(game)
+        .into_iter()
+        .map(|strategy| (strategy, Analysis::new(game, rounds, strategy).total()))
+        .collect();
+    totals.sort_by_key(|(_, total)| Reverse(*total));
+    totals
+}
+
+#[test]
+fn tests() {
+    use super::{rounds, OUTCOMES, ROCK_PAPER_SCISSORS, ROCK_PAPER_SCISSORS_LIZARD_SPOCK, SHAPES};
+
+    let game = ROCK_PAPER_SCISSORS;
+    let rounds = rounds(include_str!("../example.txt")).unwrap();
+
+    let analysis = Analysis::new(&game, &rounds, SHAPES);
+    assert_eq!(15, analysis.total());
+    assert_eq!(24, analysis.best_total());
+    let deviations: Vec<usize> = analysis.deviations().map(|(round, _)| round).collect();
+    assert_eq!(vec![2, 3], deviations);
+    assert_eq!(
+        "Round 1: Paper against Rock, Win: 2 + 6 = 8\n\
+         Round 2: Rock against Paper, Loss: 1 + 0 = 1, Scissors would score 9\n\
+         Round 3: Scissors against Scissors, Draw: 3 + 3 = 6, Rock would score 7\n\
+         Total: 15, at best 24, with 2 rounds short of the best\n",
+        analysis.to_string()
+    );
+
+    let analysis = Analysis::new(&game, &rounds, OUTCOMES);
+    assert_eq!(12, analysis.total());
+
+    let distribution = distribution(&game, &rounds);
+    assert_eq!(12, distribution.len());
+    assert!(distribution.contains(&(SHAPES, 15)));
+    assert!(distribution.contains(&(OUTCOMES, 12)));
+    assert_eq!(24, distribution[0].1);
+    assert!(distribution.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+    // Three of five shapes can be picked in ten ways, each in six orders.
+    assert_eq!(66, strategies(&ROCK_PAPER_SCISSORS_LIZARD_SPOCK).len());
+}
@@ -0,0 +1,222 @@
+use common::error::{ParseError, Source};
+
+pub mod analysis;
+
+const DAY: u8 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+    Lizard,
+    Spock,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// The rules of a cyclic game, where every shape beats the half of the other shapes coming right
+/// before it in the cycle, and loses to the other half. A shape scores its position in the cycle,
+/// starting at 1.
+#[derive(Clone, Copy, Debug)]
+pub struct Game {
+    shapes: &'static [Shape],
+}
+
+pub const ROCK_PAPER_SCISSORS: Game = Game::new(&[Shape::Rock, Shape::Paper, Shape::Scissors]);
+
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: Game = Game::new(&[
+    Shape::Rock,
+    Shape::Spock,
+    Shape::Paper,
+    Shape::Lizard,
+    Shape::Scissors,
+]);
+
+impl Game {
+    /// Returns the game played with |shapes| in the order of the cycle, which must be odd in
+    /// number so that no two shapes draw.
+    pub const fn new(shapes: &'static [Shape]) -> Game {
+        assert!(
+            shapes.len() % 2 == 1,
+            "A cyclic game needs an odd number of shapes"
+        );
+        Game { shapes }
+    }
+
+    pub fn shapes(&self) -> &'static [Shape] {
+        self.shapes
+    }
+
+    fn position(&self, shape: Shape) -> usize {
+        self.shapes
+            .iter()
+            .position(|other| *other == shape)
+            .unwrap_or_else(|| panic!("{shape:?} is not played in this game"))
+    }
+
+    /// Returns the outcome of playing |ours| against |theirs|.
+    pub fn outcome(&self, ours: Shape, theirs: Shape) -> Outcome {
+        let count = self.shapes.len();
+        let distance = (self.position(ours) + count - self.position(theirs)) % count;
+        if distance == 0 {
+            Outcome::Draw
+        } else if distance <= count / 2 {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// Returns the shape to play against |theirs| to reach |outcome|, the one closest to |theirs|
+    /// in the cycle if there are several.
+    pub fn shape_for(&self, theirs: Shape, outcome: Outcome) -> Shape {
+        let count = self.shapes.len();
+        let offset = match outcome {
+            Outcome::Loss => count - 1,
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+        };
+        self.shapes[(self.position(theirs) + offset) % count]
+    }
+
+    /// Returns the score of playing |shape|, whatever the outcome.
+    pub fn shape_score(&self, shape: Shape) -> u32 {
+        self.position(shape) as u32 + 1
+    }
+
+    /// Returns our score for playing |ours| against |theirs|.
+    pub fn score(&self, ours: Shape, theirs: Shape) -> u32 {
+        self.shape_score(ours) + self.outcome(ours, theirs).score()
+    }
+
+    /// Returns the shape scoring the most against |theirs|.
+    pub fn best(&self, theirs: Shape) -> Shape {
+        *self
+            .shapes
+            .iter()
+            .rev()
+            .max_by_key(|ours| self.score(**ours, theirs))
+            .unwrap()
+    }
+}
+
+/// How to decode X, Y and Z in our column of the strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// X, Y and Z are the shapes to play.
+    Shapes([Shape; 3]),
+    /// X, Y and Z are the outcomes to reach.
+    Outcomes([Outcome; 3]),
+}
+
+/// The decoding of the first part of the puzzle.
+pub const SHAPES: Strategy = Strategy::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]);
+
+/// The decoding of the second part of the puzzle.
+pub const OUTCOMES: Strategy = Strategy::Outcomes([Outcome::Loss, Outcome::Draw, Outcome::Win]);
+
+impl Strategy {
+    /// Returns the shape to play against |theirs| when our column holds the |column|th letter.
+    pub fn shape(self, game: &Game, theirs: Shape, column: usize) -> Shape {
+        match self {
+            Strategy::Shapes(shapes) => shapes[column],
+            Strategy::Outcomes(outcomes) => game.shape_for(theirs, outcomes[column]),
+        }
+    }
+}
+
+/// Returns the opponent's shape and the index of the letter in our column for every round.
+pub fn rounds(input: &str) -> Result<Vec<(Shape, usize)>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| {
+            let line = line.trim();
+            let mut parts = line.split(' ');
+            let opponent = source.next(&mut parts, line, "A, B or C")?;
+            let opponent = ["A", "B", "C"]
+                .iter()
+                .position(|letter| *letter == opponent)
+                .ok_or_else(|| source.error(opponent, "A, B or C"))?;
+            let ours = source.next(&mut parts, line, "X, Y or Z")?;
+            let ours = ["X", "Y", "Z"]
+                .iter()
+                .position(|letter| *letter == ours)
+                .ok_or_else(|| source.error(ours, "X, Y or Z"))?;
+            Ok((ROCK_PAPER_SCISSORS.shapes[opponent], ours))
+        })
+        .collect()
+}
+
+/// Returns the total score of following the strategy guide, read with |strategy|.
+fn total(input: &str, strategy: Strategy) -> Result<u32, ParseError> {
+    let game = ROCK_PAPER_SCISSORS;
+    Ok(rounds(input)?
+        .into_iter()
+        .map(|(theirs, column)| game.score(strategy.shape(&game, theirs, column), theirs))
+        .sum())
+}
+
+/// Returns the total score when X, Y and Z are the shapes to play.
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    total(input, SHAPES)
+}
+
+/// Returns the total score when X, Y and Z are the outcomes to reach.
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    total(input, OUTCOMES)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(15, part1(example).unwrap());
+    assert_eq!(12, part2(example).unwrap());
+
+    let game = ROCK_PAPER_SCISSORS;
+    assert_eq!(Outcome::Win, game.outcome(Shape::Rock, Shape::Scissors));
+    assert_eq!(Outcome::Loss, game.outcome(Shape::Rock, Shape::Paper));
+    assert_eq!(8, game.score(Shape::Paper, Shape::Rock));
+    assert_eq!(Shape::Scissors, game.best(Shape::Paper));
+
+    // Every shape of an n-move game beats (n - 1) / 2 shapes, and the shape chosen for an outcome
+    // always reaches it.
+    let game = ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+    for ours in game.shapes() {
+        let wins = game
+            .shapes()
+            .iter()
+            .filter(|theirs| game.outcome(*ours, **theirs) == Outcome::Win)
+            .count();
+        assert_eq!(2, wins);
+        for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+            assert_eq!(outcome, game.outcome(game.shape_for(*ours, outcome), *ours));
+        }
+    }
+    assert_eq!(Outcome::Win, game.outcome(Shape::Spock, Shape::Scissors));
+    assert_eq!(Outcome::Win, game.outcome(Shape::Lizard, Shape::Spock));
+    assert_eq!(Outcome::Win, game.outcome(Shape::Rock, Shape::Lizard));
+    assert_eq!(Outcome::Loss, game.outcome(Shape::Paper, Shape::Lizard));
+
+    assert_eq!(
+        "Day 2, line 2, column 3: expected X, Y or Z, found \"W\"",
+        part1("A X\nB W").unwrap_err().to_string()
+    );
+}
@@ -1,82 +1,46 @@
-use std::fs;
+//! Usage: `day02 [input] [--analyse]`, in any order. The input defaults to `input.txt`.
+//!
+//! With `--analyse`, both decodings of the strategy guide are explained round by round after the
+//! answers, followed by the total of every other decoding.
 
-fn main() {
-    let file = fs::read_to_string("input.txt").unwrap();
-    let result: u32 = file.trim().split('\n').map(|line| {
-        let mut parts = line.trim().split(' ');
-        let opponent = parts.next().unwrap();
-        let ours = parts.next().unwrap();
-
-        // A for Rock, B for Paper, and C for Scissors.
-        let ours = match ours {
-            "X" => "A",
-            "Y" => "B",
-            "Z" => "C",
-            _ => panic!("Unexpected input {}", ours)
-        };
-        let multiplier = if ours == opponent {
-            3
-        } else if (ours == "A" && opponent == "C") ||
-        (ours == "B" && opponent == "A") ||
-        (ours == "C" && opponent == "B") {
-            6
-        } else {
-            0
-        };
-
-        multiplier + match ours {
-            "A" => 1,
-            "B" => 2,
-            "C" => 3,
-            _ => panic!("Unexpected input {}", ours)
-        }
-    }).sum();
-
-    println!("First part:");
-    println!("The result is: {result}");
+use std::{env, process};
 
-    let result: u32 = file.trim().split('\n').map(|line| {
-        let mut parts = line.trim().split(' ');
-        let opponent = parts.next().unwrap();
-        let ours = parts.next().unwrap();
+use common::{error::or_exit, input};
+use day02::{
+    analysis::{self, Analysis},
+    Strategy, OUTCOMES, ROCK_PAPER_SCISSORS, SHAPES,
+};
 
-        // X loss, Y tie, Z win.
-        let ours = match ours {
-            "X" => {  // loss
-                match opponent {
-                    "A" => "C",
-                    "B" => "A",
-                    "C" => "B",
-                    _ => panic!(),
-                }
+fn main() {
+    let mut path = String::from(input::DEFAULT_PATH);
+    let mut analyse = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--analyse" => analyse = true,
+            _ if arg.starts_with("--") => {
+                eprintln!("Unknown argument {arg}");
+                process::exit(1);
             }
-            "Y" => opponent,
-            "Z" => 
-                match opponent {
-                    "A" => "B",
-                    "B" => "C",
-                    "C" => "A",
-                    _ => panic!(),
-                }
-            _ => panic!("Unexpected input {}", ours)
-        };
-        let multiplier = if ours == opponent {
-            3
-        } else if (ours == "A" && opponent == "C") ||
-        (ours == "B" && opponent == "A") ||
-        (ours == "C" && opponent == "B") {
-            6
-        } else {
-            0
-        };
-
-        multiplier + match ours {
-            "A" => 1,
-            "B" => 2,
-            "C" => 3,
-            _ => panic!("Unexpected input {}", ours)
+            _ => path = arg,
+        }
+    }
+    let file = input::read_or_exit(&path);
+    println!("Part 1: {}", or_exit(day02::part1(&file)));
+    println!("Part 2: {}", or_exit(day02::part2(&file)));
+    if analyse {
+        let game = ROCK_PAPER_SCISSORS;
+        let rounds = or_exit(day02::rounds(&file));
+        for (name, strategy) in [("shapes", SHAPES), ("outcomes", OUTCOMES)] {
+            println!("\nX, Y and Z as {name}:");
+            print!("{}", Analysis::new(&game, &rounds, strategy));
+        }
+        println!("\nEvery decoding of X, Y and Z:");
+        for (strategy, total) in analysis::distribution(&game, &rounds) {
+            let table = match strategy {
+                Strategy::Shapes(shapes) => format!("{shapes:?}"),
+                Strategy::Outcomes(outcomes) => format!("{outcomes:?}"),
+            };
+            println!("{table}: {total}");
         }
-    }).sum();
-    println!("Second part:");
-    println!("The result is: {result}");
-}
\ No newline at end of file
+    }
+}
@@ -0,0 +1,153 @@
+use std::num::NonZeroUsize;
+
+use common::error::{ParseError, Source};
+
+const DAY: u8 = 3;
+
+/// The number of rucksacks of a group of elves.
+const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+/// Returns the priority of |character|, or None if it is not an item letter.
+fn priority(character: u8) -> Option<u8> {
+    match character {
+        b'a'..=b'z' => Some(character - b'a' + 1),
+        b'A'..=b'Z' => Some(character - b'A' + 27),
+        _ => None,
+    }
+}
+
+/// A set of items, as a bit per item at its priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// Returns the set of |items|, or None if any of them is not a letter.
+    pub fn from(items: &[u8]) -> Option<Items> {
+        items
+            .iter()
+            .try_fold(0, |mask, item| Some(mask | 1 << priority(*item)?))
+            .map(Items)
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the priority of every item of the set, from the lowest.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+    }
+}
+
+/// Returns the items found in every rucksack of |group|, or None if any item is not a letter.
+pub fn common_items(group: &[&[u8]]) -> Option<Items> {
+    let mut common: Option<Items> = None;
+    for rucksack in group {
+        let items = Items::from(rucksack)?;
+        common = Some(common.map_or(items, |common| common.intersection(items)));
+    }
+    Some(common.unwrap_or_default())
+}
+
+/// Returns the items in every rucksack.
+fn rucksacks(input: &str) -> Result<Vec<&str>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| {
+            let line = line.trim();
+            let invalid = line
+                .char_indices()
+                .find(|(_, item)| !item.is_ascii_alphabetic());
+            if let Some((index, _)) = invalid {
+                return Err(source.char_error(line, index, "an item letter"));
+            }
+            Ok(line)
+        })
+        .collect()
+}
+
+/// Returns the sum of the priorities of the items in both compartments of each rucksack.
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let source = Source::new(DAY, input);
+    let mut result = 0;
+    for rucksack in rucksacks(input)? {
+        if rucksack.len() % 2 != 0 {
+            return Err(source.error(rucksack, "an even number of items"));
+        }
+        let (first, second) = rucksack.as_bytes().split_at(rucksack.len() / 2);
+        let common = common_items(&[first, second]).expect("The items are letters");
+        if common.is_empty() {
+            return Err(source.error(rucksack, "an item in both compartments"));
+        }
+        result += common.priorities().sum::<u32>();
+    }
+    Ok(result)
+}
+
+/// Returns the sum of the priorities of the items carried by every elf of each group of
+/// |group_size| elves.
+pub fn badges(input: &str, group_size: NonZeroUsize) -> Result<u32, ParseError> {
+    let source = Source::new(DAY, input);
+    let rucksacks = rucksacks(input)?;
+    let group_size = group_size.get();
+    let mut result = 0;
+    for group in rucksacks.chunks(group_size) {
+        let last = group.last().unwrap();
+        if group.len() < group_size {
+            return Err(source.missing(last, format!("{group_size} rucksacks in the group")));
+        }
+        let items: Vec<&[u8]> = group.iter().map(|rucksack| rucksack.as_bytes()).collect();
+        let common = common_items(&items).expect("The items are letters");
+        if common.is_empty() {
+            return Err(source.error(last, "an item shared with the rest of the group"));
+        }
+        result += common.priorities().sum::<u32>();
+    }
+    Ok(result)
+}
+
+/// Returns the sum of the priorities of the badges of each group of three elves.
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    badges(input, GROUP_SIZE)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(157, part1(example).unwrap());
+    assert_eq!(70, part2(example).unwrap());
+
+    let items = common_items(&[b"abcXz", b"zcaY", b"Zzac"]).unwrap();
+    assert_eq!(vec![1, 3, 26], items.priorities().collect::<Vec<_>>());
+    assert_eq!(Items::from(b"Zz"), common_items(&[b"zZ"]));
+    assert!(common_items(&[]).unwrap().is_empty());
+    assert_eq!(None, Items::from(b"a1"));
+    assert_eq!(None, common_items(&[b"ab", b"b "]));
+
+    let size = |size| NonZeroUsize::new(size).unwrap();
+    assert_eq!(1 + 2 + 2 + 3, badges("ab\nbc", size(1)).unwrap());
+    assert_eq!(2 + 2, badges("ab\nbc\nbd\nbe", size(2)).unwrap());
+    assert_eq!(
+        "Day 3, line 3, column 3: expected 2 rucksacks in the group, found nothing",
+        badges("ab\nbc\nbd", size(2)).unwrap_err().to_string()
+    );
+
+    assert_eq!(
+        "Day 3, line 1, column 1: expected an even number of items, found \"abc\"",
+        part1("abc").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Day 3, line 2, column 1: expected an item in both compartments, found \"abcd\"",
+        part1("abca\nabcd").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Day 3, line 3, column 1: expected an item shared with the rest of the group, found \"b\"",
+        part2("a\na\nb").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,76 @@
+use std::ops::Range;
+
+use common::{
+    error::{ParseError, Source},
+    interval::IntervalSet,
+    pattern::Pattern,
+};
+
+type Pair = (Range<i64>, Range<i64>);
+
+const DAY: u8 = 4;
+/// Two inclusive ranges of sections, such as "2-4,6-8".
+const PAIR: Pattern = Pattern::new("{a range of sections},{a range of sections}");
+const RANGE: Pattern = Pattern::new("{a section number}-{a section number}");
+
+/// Returns the sections of the inclusive |range|, which must not end before it starts.
+fn to_range<'a>(source: &Source<'a>, range: &'a str) -> Result<Range<i64>, ParseError> {
+    let mut fields = RANGE.parse(source, range)?;
+    let (start, end): (i64, i64) = (fields.parse()?, fields.parse()?);
+    if end < start {
+        return Err(source.error(range, "a range ending at or after its start"));
+    }
+    Ok(start..end + 1)
+}
+
+fn to_pairs<'a>(source: &Source<'a>, line: &'a str) -> Result<Pair, ParseError> {
+    let mut fields = PAIR.parse(source, line.trim())?;
+    Ok((
+        to_range(source, fields.text())?,
+        to_range(source, fields.text())?,
+    ))
+}
+
+fn pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| to_pairs(&source, line))
+        .collect()
+}
+
+/// Returns the number of pairs where one range fully contains the other.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(pairs(input)?
+        .into_iter()
+        .filter(|(first, second)| {
+            IntervalSet::from(first.clone()).covers(second)
+                || IntervalSet::from(second.clone()).covers(first)
+        })
+        .count())
+}
+
+/// Returns the number of pairs where the ranges overlap.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(pairs(input)?
+        .into_iter()
+        .filter(|(first, second)| IntervalSet::from(first.clone()).overlaps(second))
+        .count())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(2, part1(example).unwrap());
+    assert_eq!(4, part2(example).unwrap());
+
+    assert_eq!(
+        "Day 4, line 1, column 1: expected a range ending at or after its start, found \"5-3\"",
+        part1("5-3,1-1").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Day 4, line 2, column 5: expected a section number, found \"x\"",
+        part2("1-1,2-2\n1-1,x-2").unwrap_err().to_string()
+    );
+}
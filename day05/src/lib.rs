@@ -0,0 +1,139 @@
+use common::{
+    error::{ParseError, Source},
+    pattern::Pattern,
+};
+
+const DAY: u8 = 5;
+const INSTRUCTION: Pattern = Pattern::new("move {a number of crates} from {a stack} to {a stack}");
+
+struct Instruction {
+    from: usize,
+    to: usize,
+    count: usize,
+}
+
+/// Parses a 1-based stack number, which must not exceed |stack_count|.
+fn parse_stack(source: &Source, word: &str, stack_count: usize) -> Result<usize, ParseError> {
+    let expected = format!("a stack between 1 and {stack_count}");
+    match source.parse::<usize>(word, &expected)? {
+        stack if (1..=stack_count).contains(&stack) => Ok(stack),
+        _ => Err(source.error(word, expected)),
+    }
+}
+
+fn parse_instruction<'a>(
+    source: &Source<'a>,
+    line: &'a str,
+    stack_count: usize,
+) -> Result<Instruction, ParseError> {
+    let mut fields = INSTRUCTION.parse(source, line.trim())?;
+    let count = fields.parse()?;
+    let from = parse_stack(source, fields.text(), stack_count)?;
+    let to = parse_stack(source, fields.text(), stack_count)?;
+    Ok(Instruction { from, to, count })
+}
+
+fn result(stacks: Vec<Vec<char>>) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.last().unwrap())
+        .fold(String::new(), |mut string, stack| {
+            string.push(*stack);
+            string
+        })
+}
+
+/// Returns the initial stacks and the instructions.
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+    let source = Source::new(DAY, input);
+
+    // Find the line the stack indices.
+    let (divider_index, divider_line) = input
+        .split('\n')
+        .enumerate()
+        .find(|line| line.1.starts_with(" 1"))
+        .ok_or_else(|| source.missing(input, "a line numbering the stacks"))?;
+
+    // Find the largest number in said line, that's the number of stacks.
+    let stack_count = divider_line
+        .split(' ')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|num| source.parse::<usize>(num, "a stack number"))
+        .collect::<Result<Vec<usize>, ParseError>>()?
+        .into_iter()
+        .max()
+        .unwrap();
+    let mut parsed_stacks: Vec<Vec<char>> = Vec::new();
+    parsed_stacks.resize(stack_count, Vec::new());
+
+    // Stack the boxes.
+    let lines: Vec<&str> = input.split('\n').take(divider_index).collect();
+    for line in lines.iter().rev() {
+        let mut iter = line.char_indices();
+        // Eat the first [
+        iter.next();
+
+        let mut index = 0;
+        while let Some((position, char)) = iter.next() {
+            if char != ' ' {
+                let stack = parsed_stacks.get_mut(index).ok_or_else(|| {
+                    source.char_error(line, position, format!("at most {stack_count} stacks"))
+                })?;
+                stack.push(char);
+            }
+            // Eat the ] [
+            iter.next();
+            iter.next();
+            iter.next();
+            index += 1;
+        }
+    }
+    let instructions = input
+        .trim()
+        .split('\n')
+        .skip(divider_index + 2)
+        .map(|line| parse_instruction(&source, line, stack_count))
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    Ok((parsed_stacks, instructions))
+}
+
+/// Returns the crates on top of each stack after moving them one at a time.
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse(input)?;
+
+    // Execute the instructions as part 1.
+    for instruction in &instructions {
+        for _ in 0..instruction.count {
+            let target = stacks[instruction.from - 1].pop().unwrap();
+            stacks[instruction.to - 1].push(target);
+        }
+    }
+
+    Ok(result(stacks))
+}
+
+/// Returns the crates on top of each stack after moving them several at a time.
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, instructions) = parse(input)?;
+
+    // Execute the instructions as part 2.
+    for instruction in &instructions {
+        let mut temp: Vec<char> = Vec::new();
+        for _ in 0..instruction.count {
+            temp.push(stacks[instruction.from - 1].pop().unwrap());
+        }
+        for _ in 0..instruction.count {
+            stacks[instruction.to - 1].push(temp.pop().unwrap());
+        }
+    }
+
+    Ok(result(stacks))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!("CMZ", part1(example).unwrap());
+    assert_eq!("MCD", part2(example).unwrap());
+}
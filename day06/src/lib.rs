@@ -0,0 +1,42 @@
+use common::error::{ParseError, Source};
+use std::collections::HashSet;
+
+const DAY: u8 = 6;
+
+/// Returns the number of characters processed before the start-of-packet marker.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let bytes = input.trim().as_bytes();
+    for i in 3..bytes.len() {
+        let mut set: HashSet<u8> = HashSet::new();
+        set.insert(bytes[i - 3]);
+        set.insert(bytes[i - 2]);
+        set.insert(bytes[i - 1]);
+        set.insert(bytes[i]);
+        if set.len() >= 4 {
+            return Ok(i + 1);
+        }
+    }
+    Err(Source::new(DAY, input).missing(input.trim(), "a start-of-packet marker"))
+}
+
+/// Returns the number of characters processed before the start-of-message marker.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let bytes = input.trim().as_bytes();
+    for i in 14..bytes.len() {
+        let mut set: HashSet<u8> = HashSet::new();
+        for j in 0..14 {
+            set.insert(bytes[i - j]);
+            if set.len() >= 14 {
+                return Ok(i + 1);
+            }
+        }
+    }
+    Err(Source::new(DAY, input).missing(input.trim(), "a start-of-message marker"))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(7, part1(example).unwrap());
+    assert_eq!(19, part2(example).unwrap());
+}
@@ -0,0 +1,75 @@
+use common::error::{ParseError, Source};
+use std::collections::HashMap;
+
+const DAY: u8 = 7;
+
+const TOTAL_DISK: u64 = 70000000;
+const MIN_FREE: u64 = 30000000;
+
+/// Returns the total size of every folder, keyed by its path.
+fn folders(input: &str) -> Result<HashMap<String, u64>, ParseError> {
+    let source = Source::new(DAY, input);
+    let mut current: Vec<&str> = vec![""];
+    let mut folders: HashMap<String, u64> = HashMap::new();
+    folders.insert(String::from(""), 0);
+    for line in input.trim().split('\n') {
+        let line = line.trim();
+        // Parse commands.
+        if line == "$ cd /" {
+            current = vec![""];
+            continue;
+        }
+        if line.starts_with("$ cd ..") {
+            current.pop();
+            continue;
+        }
+        if line.starts_with("$ cd") {
+            current.push(&line[5..]);
+            let folder = current.join("/");
+            folders.entry(folder).or_insert(0);
+            continue;
+        }
+        // Ignore $ ls and dir.
+        if line.starts_with("$ ls") || line.starts_with("dir") {
+            continue;
+        }
+        // Parse files.
+        let size: u64 = source.parse_next(&mut line.split(' '), line, "a file size")?;
+        for index in 0..current.len() {
+            let folder = current[0..index + 1].join("/");
+            let current_size = folders.get(&folder).unwrap();
+            folders.insert(folder, size + current_size);
+        }
+    }
+    Ok(folders)
+}
+
+/// Returns the combined size of every folder of at most 100000.
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    let folders = folders(input)?;
+    Ok(folders
+        .iter()
+        .filter(|(_, size)| **size <= 100000)
+        .map(|(_, size)| size)
+        .sum())
+}
+
+/// Returns the size of the smallest folder that frees up enough space when deleted.
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let folders = folders(input)?;
+    let total_size = folders.get("").unwrap();
+    let free_space = TOTAL_DISK - total_size;
+    let size_to_free = MIN_FREE - free_space;
+    Ok(*folders
+        .values()
+        .filter(|size| **size >= size_to_free)
+        .min()
+        .unwrap())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(95437, part1(example).unwrap());
+    assert_eq!(24933642, part2(example).unwrap());
+}
@@ -0,0 +1,68 @@
+use common::{
+    error::{ParseError, Source},
+    grid::{Grid, Point, ORTHOGONAL},
+};
+
+const DAY: u8 = 8;
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    let source = Source::new(DAY, input);
+    Grid::parse(&source, input, "a tree height", |character| {
+        character.to_digit(10).map(|height| height as u8)
+    })
+}
+
+/// Returns the trees seen from |point| when looking towards |direction|, closest first.
+fn line_of_sight(
+    grid: &Grid<u8>,
+    point: Point,
+    direction: (isize, isize),
+) -> impl Iterator<Item = Point> + '_ {
+    std::iter::successors(grid.step(point, direction), move |tree| {
+        grid.step(*tree, direction)
+    })
+}
+
+/// Returns the number of trees visible from outside the grid.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let grid = parse(input)?;
+    Ok(grid
+        .iter()
+        .filter(|(point, height)| {
+            ORTHOGONAL.iter().any(|direction| {
+                line_of_sight(&grid, *point, *direction).all(|tree| grid[tree] < **height)
+            })
+        })
+        .count())
+}
+
+/// Returns the highest scenic score of any tree.
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    let grid = parse(input)?;
+    Ok(grid
+        .iter()
+        .map(|(point, height)| {
+            ORTHOGONAL
+                .iter()
+                .map(|direction| {
+                    let mut distance = 0;
+                    for tree in line_of_sight(&grid, point, *direction) {
+                        distance += 1;
+                        if grid[tree] >= *height {
+                            break;
+                        }
+                    }
+                    distance
+                })
+                .product()
+        })
+        .max()
+        .unwrap())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(21, part1(example).unwrap());
+    assert_eq!(8, part2(example).unwrap());
+}
@@ -0,0 +1,136 @@
+use common::{
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::Grid,
+    pattern::Pattern,
+};
+use std::collections::HashSet;
+
+const DAY: u8 = 9;
+const COMMAND: Pattern = Pattern::new("{a direction} {a number of steps}");
+/// How far from the head the frames show, sideways and up or down.
+const VIEW_RADIUS: (i32, i32) = (30, 15);
+/// Empty cells, the positions visited by the tail, the head and every other knot.
+const PALETTE: Palette = &[
+    ('.', [20, 20, 30]),
+    ('#', [70, 90, 140]),
+    ('H', [240, 80, 60]),
+    ('K', [240, 200, 80]),
+];
+
+struct Command<'a> {
+    direction: &'a str,
+    steps: u32,
+}
+
+fn pull(head: i32, tail: &mut i32) {
+    *tail += (head - *tail).signum();
+}
+
+fn move_head(direction: &str, head: &mut (i32, i32)) {
+    match direction {
+        "R" => head.0 += 1,
+        "L" => head.0 -= 1,
+        "U" => head.1 += 1,
+        "D" => head.1 -= 1,
+        _ => unreachable!("Directions are checked while parsing"),
+    }
+}
+
+fn move_tail(head: &(i32, i32), tail: &mut (i32, i32)) {
+    if (tail.0 - head.0).abs() > 1 {
+        // X displacement.
+        pull(head.0, &mut tail.0);
+        if (tail.1 - head.1).abs() > 0 {
+            // Diagonal displacement.
+            pull(head.1, &mut tail.1);
+        }
+    } else if (tail.1 - head.1).abs() > 1 {
+        // Y displacement.
+        pull(head.1, &mut tail.1);
+        if (tail.0 - head.0).abs() > 0 {
+            // Diagonal displacement.
+            pull(head.0, &mut tail.0);
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Command<'_>>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| {
+            let mut fields = COMMAND.parse(&source, line.trim())?;
+            let direction = fields.text();
+            if !matches!(direction, "R" | "L" | "U" | "D") {
+                return Err(source.error(direction, "R, L, U or D"));
+            }
+            let steps = fields.parse()?;
+            Ok(Command { direction, steps })
+        })
+        .collect()
+}
+
+/// Returns the rope around its head, upside up, along with the |positions| visited by its tail.
+fn draw(rope: &[(i32, i32)], positions: &HashSet<(i32, i32)>) -> Grid<char> {
+    let (radius_x, radius_y) = VIEW_RADIUS;
+    let head = rope[0];
+    let mut view = Grid::new(2 * radius_x as usize + 1, 2 * radius_y as usize + 1, '.');
+    let mut paint = |position: &(i32, i32), cell: char| {
+        let x = position.0 - head.0 + radius_x;
+        let y = head.1 - position.1 + radius_y;
+        if let Some(view) = view
+            .get_mut((x as usize, y as usize))
+            .filter(|_| x >= 0 && y >= 0)
+        {
+            *view = cell;
+        }
+    };
+    for position in positions {
+        paint(position, '#');
+    }
+    for knot in rope.iter().rev() {
+        paint(knot, 'K');
+    }
+    paint(&head, 'H');
+    view
+}
+
+/// Returns the number of positions visited by the tail of a rope with |knots| knots, recording
+/// every step as a frame of |task|.
+fn visited(input: &str, knots: usize, task: &str) -> Result<usize, ParseError> {
+    let mut positions: HashSet<(i32, i32)> = HashSet::new();
+    let mut rope: Vec<(i32, i32)> = vec![(0, 0); knots];
+    positions.insert(*rope.last().unwrap());
+    for command in parse(input)? {
+        for _ in 0..command.steps {
+            move_head(command.direction, &mut rope[0]);
+            for i in 0..rope.len() - 1 {
+                let head = rope[i];
+                move_tail(&head, &mut rope[i + 1]);
+            }
+            positions.insert(*rope.last().unwrap());
+            frame::record(task, PALETTE, || draw(&rope, &positions));
+        }
+    }
+
+    Ok(positions.len())
+}
+
+/// Returns the number of positions visited by the tail of a rope with two knots.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    visited(input, 2, "Pulling a rope with two knots")
+}
+
+/// Returns the number of positions visited by the tail of a rope with ten knots.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    visited(input, 10, "Pulling a rope with ten knots")
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(13, part1(example).unwrap());
+    assert_eq!(1, part2(example).unwrap());
+}
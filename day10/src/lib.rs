@@ -0,0 +1,122 @@
+use common::{
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::Grid,
+    pattern::Pattern,
+};
+
+const DAY: u8 = 10;
+/// Dark and lit pixels, the pixel being drawn, and the sprite shown below the screen.
+const PALETTE: Palette = &[
+    ('.', [15, 25, 15]),
+    ('#', [90, 230, 110]),
+    ('@', [240, 240, 240]),
+    ('=', [200, 160, 60]),
+];
+const INSTRUCTIONS: [Pattern; 2] = [Pattern::new("noop"), Pattern::new("addx {an addx operand}")];
+
+enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+impl Instruction {
+    fn from<'a>(source: &Source<'a>, line: &'a str) -> Result<Instruction, ParseError> {
+        match Pattern::parse_any(&INSTRUCTIONS, source, line.trim())? {
+            (0, _) => Ok(Instruction::Noop),
+            (_, mut fields) => Ok(Instruction::AddX(fields.parse()?)),
+        }
+    }
+
+    fn cycles(&self) -> u32 {
+        match self {
+            Self::AddX(_) => 2,
+            Self::Noop => 1,
+        }
+    }
+}
+
+/// Returns |screen| as it is being drawn at |clock|, above the sprite at |register_x|.
+fn draw(screen: &[Vec<char>], clock: i32, register_x: i32) -> Grid<char> {
+    let mut view = Grid::new(40, screen.len() + 2, '.');
+    for (y, line) in screen.iter().enumerate() {
+        for (x, pixel) in line.iter().enumerate() {
+            view[(x, y)] = *pixel;
+        }
+    }
+    if let Some(pixel) = view.get_mut(((clock % 40) as usize, (clock / 40) as usize)) {
+        *pixel = '@';
+    }
+    for x in register_x - 1..=register_x + 1 {
+        if let Some(pixel) = view
+            .get_mut((x as usize, screen.len() + 1))
+            .filter(|_| x >= 0)
+        {
+            *pixel = '=';
+        }
+    }
+    view
+}
+
+/// Runs the program, returning the signal strength and the drawn screen. Every cycle is recorded
+/// as a frame of |task|, if given.
+fn run(input: &str, task: Option<&str>) -> Result<(i32, Vec<Vec<char>>), ParseError> {
+    let source = Source::new(DAY, input);
+    let mut clock: i32 = 0;
+    let mut register_x: i32 = 1;
+    let mut strength = 0;
+    let mut screen = vec![vec!['.'; 40]; 6];
+    for line in input.trim().split('\n') {
+        let instruction = Instruction::from(&source, line)?;
+        for _ in 0..instruction.cycles() {
+            if let Some(task) = task {
+                frame::record(task, PALETTE, || draw(&screen, clock, register_x));
+            }
+            let currently_drawn_x = clock % 40;
+            if (register_x - currently_drawn_x).abs() <= 1 {
+                let currently_drawn_y = clock / 40;
+                screen[currently_drawn_y as usize][currently_drawn_x as usize] = '#';
+            }
+            clock += 1;
+            if (clock + 20) % 40 == 0 {
+                strength += clock * register_x;
+            }
+        }
+        match instruction {
+            Instruction::Noop => (),
+            Instruction::AddX(operand) => register_x += operand,
+        }
+    }
+
+    Ok((strength, screen))
+}
+
+/// Returns the sum of the signal strengths during the 20th, 60th, 100th... cycles.
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    let (strength, _) = run(input, None)?;
+    Ok(strength)
+}
+
+/// Returns the image drawn on the CRT, one line per row.
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let (_, screen) = run(input, Some("Drawing the screen"))?;
+    Ok(screen
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(13140, part1(example).unwrap());
+    let image = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+    assert_eq!(image, part2(example).unwrap());
+}
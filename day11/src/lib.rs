@@ -0,0 +1,234 @@
+use common::{
+    error::{ParseError, Source},
+    pattern::{Fields, Pattern},
+};
+use std::collections::VecDeque;
+
+const DAY: u8 = 11;
+/// Every line of a monkey, once trimmed.
+const MONKEY: [Pattern; 6] = [
+    Pattern::new("Monkey {a monkey number}:"),
+    Pattern::new("Starting items: {a worry level}"),
+    Pattern::new("Operation: new = {an operand} {+ or *} {an operand}"),
+    Pattern::new("Test: divisible by {a positive divisor}"),
+    Pattern::new("If true: throw to monkey {a monkey}"),
+    Pattern::new("If false: throw to monkey {a monkey}"),
+];
+
+const ROUNDS_PART_1: u8 = 20;
+const ROUNDS_PART_2: u64 = 10_000;
+
+#[derive(Debug)]
+enum Operation {
+    Multiply,
+    Add,
+}
+
+impl Operation {
+    fn from(source: &Source, string: &str) -> Result<Operation, ParseError> {
+        match string {
+            "+" => Ok(Operation::Add),
+            "*" => Ok(Operation::Multiply),
+            _ => Err(source.error(string, "+ or *")),
+        }
+    }
+
+    fn operate(&self, item1: i64, item2: i64) -> i64 {
+        match self {
+            Operation::Add => item1 + item2,
+            Operation::Multiply => item1 * item2,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operand {
+    Old,
+    Constant(i64),
+}
+
+impl Operand {
+    fn from(source: &Source, string: &str) -> Result<Operand, ParseError> {
+        match string {
+            "old" => Ok(Operand::Old),
+            n => Ok(Operand::Constant(source.parse(n, "old or a number")?)),
+        }
+    }
+
+    fn to(&self, old_item: i64) -> i64 {
+        match self {
+            Operand::Old => old_item,
+            Operand::Constant(constant) => *constant,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: VecDeque<i64>,
+    operation: Operation,
+    operand1: Operand,
+    operand2: Operand,
+    divisible: i64,
+    monkey_true: usize,
+    monkey_false: usize,
+    inspect_count: u64,
+}
+
+impl Monkey {
+    /// Parses a monkey, which may only throw to one of the |monkey_count| monkeys.
+    fn from<'a>(
+        source: &Source<'a>,
+        string: &'a str,
+        monkey_count: usize,
+    ) -> Result<Monkey, ParseError> {
+        let mut lines = string.split('\n').map(str::trim);
+        let mut fields = MONKEY.iter().map(|pattern| -> Result<Fields, ParseError> {
+            let line = source.next(&mut lines, string, "another line of the monkey")?;
+            pattern.parse(source, line)
+        });
+        let mut fields = || fields.next().unwrap();
+
+        // The monkeys are numbered in order.
+        fields()?;
+        let items = VecDeque::from(fields()?.parse_list(", ")?);
+        let mut operation = fields()?;
+        let operand1 = Operand::from(source, operation.text())?;
+        let operation_token = operation.text();
+        let operand2 = Operand::from(source, operation.text())?;
+        let operation = Operation::from(source, operation_token)?;
+
+        let divisible = fields()?.text();
+        let divisible = match source.parse(divisible, "a positive divisor")? {
+            0 => return Err(source.error(divisible, "a positive divisor")),
+            divisor => divisor,
+        };
+
+        let expected = format!("a monkey below {monkey_count}");
+        let mut target = || -> Result<usize, ParseError> {
+            let token = fields()?.text();
+            match source.parse(token, &expected)? {
+                monkey if monkey < monkey_count => Ok(monkey),
+                _ => Err(source.error(token, &expected)),
+            }
+        };
+        let monkey_true = target()?;
+        let monkey_false = target()?;
+        if let Some(line) = lines.find(|line| !line.is_empty()) {
+            return Err(source.error(line, "the end of the monkey"));
+        }
+        Ok(Monkey {
+            items,
+            operation,
+            operand1,
+            operand2,
+            divisible,
+            monkey_true,
+            monkey_false,
+            inspect_count: 0,
+        })
+    }
+
+    /// Returns a tuple containing the item thrown and the monkey it is thrown to.
+    fn inspect_item(&mut self) -> Option<(i64, usize)> {
+        let item = self.items.pop_front();
+        item?;
+        self.inspect_count += 1;
+        let item = item.unwrap();
+        let item = self
+            .operation
+            .operate(self.operand1.to(item), self.operand2.to(item));
+        let item = item / 3;
+        let target_monkey = if item % self.divisible == 0 {
+            self.monkey_true
+        } else {
+            self.monkey_false
+        };
+        Some((item, target_monkey))
+    }
+
+    /// Returns a tuple containing the item thrown and the monkey it is thrown to.
+    fn inspect_item2(&mut self, limit: i64) -> Option<(i64, usize)> {
+        let item = self.items.pop_front();
+        item?;
+        self.inspect_count += 1;
+        let item = item.unwrap();
+        let item = self
+            .operation
+            .operate(self.operand1.to(item), self.operand2.to(item));
+        let item = item % limit;
+        let target_monkey = if item % self.divisible == 0 {
+            self.monkey_true
+        } else {
+            self.monkey_false
+        };
+        Some((item, target_monkey))
+    }
+}
+
+fn monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let source = Source::new(DAY, input);
+    let blocks: Vec<&str> = input.trim().split("\n\n").collect();
+    blocks
+        .iter()
+        .map(|block| Monkey::from(&source, block, blocks.len()))
+        .collect()
+}
+
+fn result(monkeys: &[Monkey]) -> u64 {
+    let mut inspections: Vec<u64> = monkeys.iter().map(|monkey| monkey.inspect_count).collect();
+    inspections.sort_unstable();
+    inspections[inspections.len() - 1] * inspections[inspections.len() - 2]
+}
+
+/// Returns the monkey business after 20 rounds, dividing the worry level by three.
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    let mut monkeys = monkeys(input)?;
+
+    for _ in 0..ROUNDS_PART_1 {
+        for current_monkey in 0..monkeys.len() {
+            while let Some((item, target_monkey_index)) = monkeys[current_monkey].inspect_item() {
+                monkeys[target_monkey_index].items.push_back(item);
+            }
+        }
+    }
+    Ok(result(&monkeys))
+}
+
+/// Returns the monkey business after 10000 rounds, without dividing the worry level.
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let mut monkeys = monkeys(input)?;
+    let limit = monkeys
+        .iter()
+        .map(|monkey| monkey.divisible)
+        .reduce(|a, b| a * b)
+        .unwrap();
+    for _ in 0..ROUNDS_PART_2 {
+        for current_monkey in 0..monkeys.len() {
+            while let Some((item, target_monkey_index)) =
+                monkeys[current_monkey].inspect_item2(limit)
+            {
+                monkeys[target_monkey_index].items.push_back(item);
+            }
+        }
+    }
+    Ok(result(&monkeys))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(10605, part1(example).unwrap());
+    assert_eq!(2713310158, part2(example).unwrap());
+
+    // Every line is trimmed, whatever its indentation and trailing spaces.
+    let spaced = example.replace('\n', " \n").replace(" \n \n", " \n\n");
+    assert_eq!(10605, part1(&spaced).unwrap());
+    assert_eq!(2713310158, part2(&spaced).unwrap());
+    assert_eq!(
+        "Day 11, line 2, column 3: expected \"Starting items: \", found \"Items: 79, 98\"",
+        part1(&example.replacen("Starting items", "Items", 1))
+            .unwrap_err()
+            .to_string()
+    );
+}
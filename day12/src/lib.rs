@@ -0,0 +1,92 @@
+use common::{
+    error::{ParseError, Source},
+    grid::{Grid, Point},
+    search,
+};
+
+const DAY: u8 = 12;
+
+fn expected_distance(start: &Point, end: &Point) -> usize {
+    start.0.abs_diff(end.0) + start.1.abs_diff(end.1)
+}
+
+fn is_valid(current: Point, next: Point, grid: &Grid<u8>) -> bool {
+    grid[next] <= grid[current] + 1
+}
+
+/// Returns the shortest path from |start| to |end|, both included.
+fn shortest_path(grid: &Grid<u8>, start: Point, end: Point) -> Option<Vec<Point>> {
+    let neighbours = |current: &Point| {
+        let current = *current;
+        grid.neighbours4(current)
+            .filter(move |next| is_valid(current, *next, grid))
+            .map(|next| (next, 1))
+    };
+    let heuristic = |point: &Point| expected_distance(point, &end);
+    search::astar(start, neighbours, heuristic, |point| *point == end).map(|(_, path)| path)
+}
+
+/// Returns the height map along with the start and end coordinates.
+fn parse(input: &str) -> Result<(Grid<u8>, Point, Point), ParseError> {
+    let source = Source::new(DAY, input);
+    let mut grid = Grid::parse(
+        &source,
+        input,
+        "a height between a and z, S or E",
+        |square| matches!(square, 'a'..='z' | 'S' | 'E').then_some(square as u8),
+    )?;
+
+    let start = grid
+        .find(|square| *square == b'S')
+        .ok_or_else(|| source.missing(input, "a start S"))?;
+    grid[start] = b'a';
+    let end = grid
+        .find(|square| *square == b'E')
+        .ok_or_else(|| source.missing(input, "an end E"))?;
+    grid[end] = b'z';
+    Ok((grid, start, end))
+}
+
+/// Returns the error of an input where the end cannot be reached.
+fn no_path(input: &str) -> ParseError {
+    Source::new(DAY, input).missing(input, "a path from the start to the end")
+}
+
+/// Returns the fewest steps required to go from the start to the end.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let (grid, start, end) = parse(input)?;
+    let path = shortest_path(&grid, start, end).ok_or_else(|| no_path(input))?;
+    Ok(path.len() - 1)
+}
+
+/// Returns the fewest steps required to go from any square at elevation a to the end.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let (grid, _, end) = parse(input)?;
+    let starts: Vec<Point> = grid
+        .iter()
+        .filter(|(_, square)| **square == b'a')
+        .map(|(point, _)| point)
+        .collect();
+
+    starts
+        .iter()
+        .filter_map(|start| shortest_path(&grid, *start, end))
+        .map(|path| path.len() - 1)
+        .min()
+        .ok_or_else(|| no_path(input))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(31, part1(example).unwrap());
+    assert_eq!(29, part2(example).unwrap());
+
+    // The end is two levels above the square before it.
+    for part in [part1, part2] {
+        assert_eq!(
+            "Day 12, line 1, column 4: expected a path from the start to the end, found nothing",
+            part("SbE").unwrap_err().to_string()
+        );
+    }
+}
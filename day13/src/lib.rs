@@ -0,0 +1,162 @@
+use common::error::{ParseError, Source};
+
+const DAY: u8 = 13;
+
+#[derive(Debug)]
+enum Entry {
+    Number(u32),
+    List(Vec<Entry>),
+}
+
+impl Clone for Entry {
+    fn clone(&self) -> Self {
+        match self {
+            Entry::List(list) => Entry::List(list.clone()),
+            Entry::Number(number) => Entry::Number(*number),
+        }
+    }
+}
+
+impl Entry {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Entry, ParseError> {
+        let string = string.trim();
+        let (entry, rest) = Entry::parse(source, string)?;
+        if !rest.is_empty() {
+            return Err(source.error(rest, "the end of the packet"));
+        }
+        Ok(entry)
+    }
+
+    /// Parses the entry at the start of |string|, returning it along with the rest of |string|.
+    fn parse<'a>(source: &Source<'a>, string: &'a str) -> Result<(Entry, &'a str), ParseError> {
+        if let Some(mut rest) = string.strip_prefix('[') {
+            let mut parsed: Vec<Entry> = Vec::new();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Entry::List(parsed), rest));
+            }
+            loop {
+                let (entry, after) = Entry::parse(source, rest)?;
+                parsed.push(entry);
+                if let Some(after) = after.strip_prefix(',') {
+                    rest = after;
+                } else if let Some(after) = after.strip_prefix(']') {
+                    return Ok((Entry::List(parsed), after));
+                } else {
+                    return Err(source.char_error(after, 0, "a comma or ]"));
+                }
+            }
+        }
+        let digits = string.len()
+            - string
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return Err(source.char_error(string, 0, "a number or ["));
+        }
+        let number = source.parse(&string[..digits], "a number")?;
+        Ok((Entry::Number(number), &string[digits..]))
+    }
+
+    /// Returns a divider packet, a list holding a list holding |number|.
+    fn divider(number: u32) -> Entry {
+        Entry::List(vec![Entry::List(vec![Entry::Number(number)])])
+    }
+
+    fn normalize(&self) -> Vec<Entry> {
+        match self {
+            Entry::List(list) => list.clone(),
+            Entry::Number(number) => vec![Entry::Number(*number)],
+        }
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other).unwrap() == std::cmp::Ordering::Equal
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if let (Entry::Number(left), Entry::Number(right)) = (self, other) {
+            return left.cmp(right);
+        }
+        let left = self.normalize();
+        let right = other.normalize();
+
+        let left = left.iter();
+        let mut right = right.iter();
+        for left in left {
+            if let Some(right) = right.next() {
+                match left.cmp(right) {
+                    std::cmp::Ordering::Equal => continue,
+                    any => return any,
+                }
+            }
+            // Right side ran out of items first.
+            return std::cmp::Ordering::Greater;
+        }
+        if right.next().is_some() {
+            // Left side ran out of items first.
+            return std::cmp::Ordering::Less;
+        }
+        // Both lists had the same number of items.
+        std::cmp::Ordering::Equal
+    }
+}
+
+/// Returns the sum of the indices of the pairs that are in the right order.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let source = Source::new(DAY, input);
+    let packet_pairs = input
+        .trim()
+        .split("\n\n")
+        .map(|packet_pair| {
+            let mut packets = packet_pair.split('\n');
+            let left = source.next(&mut packets, packet_pair, "a packet")?;
+            let right = source.next(&mut packets, packet_pair, "a second packet")?;
+            Ok((Entry::from(&source, left)?, Entry::from(&source, right)?))
+        })
+        .collect::<Result<Vec<(Entry, Entry)>, ParseError>>()?;
+    Ok(packet_pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, packet_pair)| {
+            packet_pair.0.partial_cmp(&packet_pair.1).unwrap() != std::cmp::Ordering::Greater
+        })
+        .fold(0, |sum, (index, _)| sum + index + 1))
+}
+
+/// Returns the decoder key, the product of the indices of the divider packets once sorted.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let source = Source::new(DAY, input);
+    let separators = vec![Entry::divider(2), Entry::divider(6)];
+    let mut entries: Vec<Entry> = input
+        .trim()
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Entry::from(&source, line))
+        .collect::<Result<_, ParseError>>()?;
+    entries.extend(separators.clone());
+    entries.sort_unstable();
+    Ok(entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| separators.contains(entry))
+        .fold(1, |accumulator, (index, _)| accumulator * (index + 1)))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(13, part1(example).unwrap());
+    assert_eq!(140, part2(example).unwrap());
+}
@@ -0,0 +1,162 @@
+use common::{
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::{Grid, Point},
+    message,
+};
+
+const DAY: u8 = 14;
+const SOURCE: usize = 500;
+const PALETTE: Palette = &[
+    ('.', [20, 20, 30]),
+    ('#', [120, 110, 100]),
+    ('o', [230, 190, 90]),
+];
+
+fn parse_coordinate<'a>(source: &Source<'a>, string: &'a str) -> Result<Point, ParseError> {
+    let mut parts = string.split(',');
+    Ok((
+        source.parse_next(&mut parts, string, "an x coordinate")?,
+        source.parse_next(&mut parts, string, "a y coordinate")?,
+    ))
+}
+
+/// Parses a path of rock, whose points must each be in line with the previous one.
+fn parse_path<'a>(source: &Source<'a>, line: &'a str) -> Result<Vec<Point>, ParseError> {
+    let mut path: Vec<Point> = Vec::new();
+    for point in line.trim().split(" -> ") {
+        let coordinate = parse_coordinate(source, point)?;
+        if let Some(previous) = path.last() {
+            if previous.0 != coordinate.0 && previous.1 != coordinate.1 {
+                return Err(source.error(point, "a point in line with the previous one"));
+            }
+        }
+        path.push(coordinate);
+    }
+    Ok(path)
+}
+
+fn iterate_range(left: usize, right: usize) -> std::ops::RangeInclusive<usize> {
+    if left < right {
+        left..=right
+    } else {
+        right..=left
+    }
+}
+
+/// Pours sand from |source| into |cave|, recording a frame of |task| as every grain comes to rest.
+/// Returns the number of grains at rest once sand stops piling up.
+fn fill(task: &str, source: usize, mut cave: Grid<char>) -> u32 {
+    // Nothing comes to rest left of the rocks, so the frames start with them.
+    let left = cave
+        .iter()
+        .filter(|(_, cell)| **cell != '.')
+        .map(|((x, _), _)| x)
+        .min()
+        .unwrap_or(0);
+    let mut grains = 0;
+    loop {
+        let mut grain = (source, 0);
+        if cave[grain] != '.' {
+            message!("Completely filled");
+            return grains;
+        }
+        'fall: loop {
+            // Try below, then below to the left, then below to the right.
+            for offset in [(0, 1), (-1, 1), (1, 1)] {
+                match cave.step(grain, offset).map(|next| (next, cave[next])) {
+                    None => {
+                        message!("Grain falling through the void");
+                        return grains;
+                    }
+                    Some((next, '.')) => {
+                        grain = next;
+                        continue 'fall;
+                    }
+                    Some(_) => (),
+                }
+            }
+            cave[grain] = 'o';
+            grains += 1;
+            frame::record(task, PALETTE, || {
+                let mut view = Grid::new(cave.width() - left, cave.height(), '.');
+                for ((x, y), cell) in cave.iter().filter(|((x, _), _)| *x >= left) {
+                    view[(x - left, y)] = *cell;
+                }
+                view
+            });
+            break;
+        }
+    }
+}
+
+/// Returns the cave along with the x coordinate of the sand source in it. With a |floor|, the cave
+/// is wide enough for the sand to pile up all the way to the source.
+fn parse(input: &str, floor: bool) -> Result<(Grid<char>, usize), ParseError> {
+    let source = Source::new(DAY, input);
+    let lines: Vec<Vec<Point>> = input
+        .trim()
+        .split('\n')
+        .map(|line| parse_path(&source, line))
+        .collect::<Result<_, ParseError>>()?;
+    let mut max_x = SOURCE;
+    let mut max_y = 0;
+    for line in lines.iter().flatten() {
+        max_x = max_x.max(line.0);
+        max_y = max_y.max(line.1);
+    }
+
+    // Sand piles up in a triangle below the source, as wide as it is high.
+    let (offset, width, height) = if floor {
+        let height = max_y + 3;
+        let offset = height.saturating_sub(SOURCE);
+        (offset, offset + max_x.max(SOURCE + height) + 1, height)
+    } else {
+        (0, max_x + 1, max_y + 1)
+    };
+    let mut cave = Grid::new(width, height, '.');
+
+    for line in lines {
+        for index in 1..line.len() {
+            let first = line[index - 1];
+            let second = line[index];
+            if first.0 == second.0 {
+                // Vertical line.
+                for y in iterate_range(first.1, second.1) {
+                    cave[(first.0 + offset, y)] = '#';
+                }
+            } else {
+                // Horizontal line.
+                for x in iterate_range(first.0, second.0) {
+                    cave[(x + offset, first.1)] = '#';
+                }
+            }
+        }
+    }
+    if floor {
+        for x in 0..width {
+            cave[(x, height - 1)] = '#';
+        }
+    }
+
+    Ok((cave, SOURCE + offset))
+}
+
+/// Returns the units of sand that come to rest before sand falls into the abyss.
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let (cave, source) = parse(input, false)?;
+    Ok(fill("Pouring sand into the abyss", source, cave))
+}
+
+/// Returns the units of sand that come to rest on the floor before the source is blocked.
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    let (cave, source) = parse(input, true)?;
+    Ok(fill("Pouring sand onto the floor", source, cave))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(24, part1(example).unwrap());
+    assert_eq!(93, part2(example).unwrap());
+}
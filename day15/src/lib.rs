@@ -0,0 +1,111 @@
+use common::{
+    error::{ParseError, Source},
+    interval::IntervalSet,
+    pattern::Pattern,
+    progress,
+};
+use rayon::prelude::*;
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type Coordinate = (i64, i64);
+
+const DAY: u8 = 15;
+const TARGET_Y: i64 = 2_000_000;
+const SEARCH_RANGE: i64 = 4_000_000;
+const SENSOR: Pattern = Pattern::new(
+    "Sensor at x={the sensor x coordinate}, y={the sensor y coordinate}: \
+     closest beacon is at x={the beacon x coordinate}, y={the beacon y coordinate}",
+);
+
+struct Sensor {
+    position: Coordinate,
+    beacon: Coordinate,
+}
+
+impl Sensor {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Sensor, ParseError> {
+        let mut fields = SENSOR.parse(source, string.trim())?;
+        Ok(Sensor {
+            position: (fields.parse()?, fields.parse()?),
+            beacon: (fields.parse()?, fields.parse()?),
+        })
+    }
+}
+
+fn distance(sensor: &Sensor) -> i64 {
+    (sensor.position.0.abs_diff(sensor.beacon.0) + sensor.position.1.abs_diff(sensor.beacon.1))
+        as i64
+}
+
+fn sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| Sensor::from(&source, line))
+        .collect()
+}
+
+/// Returns the positions covered by |sensors| on row |y|.
+fn covered(sensors: &[Sensor], y: i64) -> IntervalSet {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let distance = distance(sensor) - y.abs_diff(sensor.position.1) as i64;
+            (distance >= 0).then(|| sensor.position.0 - distance..sensor.position.0 + distance + 1)
+        })
+        .collect()
+}
+
+/// Returns the number of positions on row |y| where a beacon cannot be present.
+fn count_row(input: &str, y: i64) -> Result<u64, ParseError> {
+    let sensors = sensors(input)?;
+    let row = covered(&sensors, y);
+    let beacons: HashSet<i64> = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.1 == y && row.contains(sensor.beacon.0))
+        .map(|sensor| sensor.beacon.0)
+        .collect();
+    Ok(row.len() - beacons.len() as u64)
+}
+
+/// Returns the tuning frequency of the only position within 0 and |search_range| on both axes
+/// where the distress beacon can be.
+fn find_beacon(input: &str, search_range: i64) -> Result<i64, ParseError> {
+    let sensors = sensors(input)?;
+    // The rows are searched in parallel, so they finish in any order.
+    let done = AtomicUsize::new(0);
+    let rows = search_range as usize + 1;
+    let beacon = (0..=search_range).into_par_iter().find_map_any(|y| {
+        let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+        progress::progress("Searching rows", done, rows);
+        let gap = covered(&sensors, y).gaps(0..search_range + 1).next()?;
+        Some(gap.start * 4_000_000 + y)
+    });
+    beacon.ok_or_else(|| Source::new(DAY, input).missing(input, "a position no sensor covers"))
+}
+
+/// Returns the number of positions on the target row where a beacon cannot be present.
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    count_row(input, TARGET_Y)
+}
+
+/// Returns the tuning frequency of the only position where the distress beacon can be.
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    find_beacon(input, SEARCH_RANGE)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(26, count_row(example, 10).unwrap());
+    assert_eq!(56000011, find_beacon(example, 20).unwrap());
+    let covered = "Sensor at x=10, y=10: closest beacon is at x=10, y=40\n";
+    assert_eq!(
+        "Day 15, line 2, column 1: expected a position no sensor covers, found nothing",
+        find_beacon(covered, 20).unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,187 @@
+use common::{
+    error::{ParseError, Source},
+    message,
+    pattern::Pattern,
+    search,
+};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod reference;
+
+const DAY: u8 = 16;
+/// A valve leads to a single other one, or to a list of them.
+const VALVES: [Pattern; 2] = [
+    Pattern::new("Valve {a valve} has flow rate={a flow rate}; tunnel leads to valve {a valve}"),
+    Pattern::new("Valve {a valve} has flow rate={a flow rate}; tunnels lead to valves {valves}"),
+];
+
+/// Returns the number of minutes it takes to go from |source| to every valve of |cave|.
+fn travel_times(source: &str, cave: &HashMap<String, Vec<String>>) -> HashMap<String, u32> {
+    search::distances(source.to_string(), |valve| cave[valve].clone())
+        .into_iter()
+        .map(|(valve, distance)| (valve, distance as u32))
+        .collect()
+}
+
+type Distances = HashMap<String, HashMap<String, u32>>;
+
+/// Records in |best| the most pressure released by opening every set of valves, as a bit per
+/// valve of |valves|, in any order within |time_left|.
+fn visit(
+    valves: &[&str],
+    distances: &Distances,
+    flows: &HashMap<String, u32>,
+    (current, time_left): (&str, u32),
+    (opened, pressure): (u32, u32),
+    best: &mut HashMap<u32, u32>,
+) {
+    let entry = best.entry(opened).or_default();
+    *entry = (*entry).max(pressure);
+    for (index, valve) in valves.iter().enumerate() {
+        let cost = distances[current][*valve] + 1;
+        if opened & 1 << index != 0 || cost > time_left {
+            continue;
+        }
+        let time_left = time_left - cost;
+        visit(
+            valves,
+            distances,
+            flows,
+            (valve, time_left),
+            (opened | 1 << index, pressure + flows[*valve] * time_left),
+            best,
+        );
+    }
+}
+
+/// Returns the most pressure released by opening every set of valves that can be opened within
+/// |time_left|, starting from AA.
+fn pressures(
+    distances: &Distances,
+    flows: &HashMap<String, u32>,
+    time_left: u32,
+) -> HashMap<u32, u32> {
+    let valves: Vec<&str> = flows
+        .keys()
+        .filter(|valve| flows[*valve] > 0)
+        .map(String::as_str)
+        .collect();
+    let mut best = HashMap::new();
+    visit(
+        &valves,
+        distances,
+        flows,
+        ("AA", time_left),
+        (0, 0),
+        &mut best,
+    );
+    best
+}
+
+/// Returns the distances between every valve worth opening, and the flow of those valves.
+fn parse(input: &str) -> Result<(Distances, HashMap<String, u32>), ParseError> {
+    let source = Source::new(DAY, input);
+    let mut cave: HashMap<String, Vec<String>> = HashMap::new();
+    let mut flows: HashMap<String, u32> = HashMap::new();
+    let mut distances: HashMap<String, HashMap<String, u32>> = HashMap::new();
+    let mut tunnels: Vec<&str> = Vec::new();
+    for line in input.trim().split('\n') {
+        let (_, mut fields) = Pattern::parse_any(&VALVES, &source, line.trim())?;
+        let valve = fields.text().to_string();
+        let flow = fields.parse()?;
+        let edges = fields.text();
+        tunnels.extend(edges.split(", "));
+        let edges: Vec<String> = edges.split(", ").map(|str| str.to_string()).collect();
+        cave.insert(valve.clone(), edges);
+        if flow != 0 {
+            flows.insert(valve, flow);
+        }
+    }
+    if !cave.contains_key("AA") {
+        return Err(source.missing(input, "valve AA"));
+    }
+    if let Some(tunnel) = tunnels.iter().find(|tunnel| !cave.contains_key(**tunnel)) {
+        return Err(source.error(tunnel, "a valve that exists"));
+    }
+    flows.insert(String::from("AA"), 0);
+
+    for node in &cave {
+        message!("{:?}", node);
+    }
+    for node in &flows {
+        message!("{:?}", node);
+    }
+
+    for node in flows.keys() {
+        distances.insert(node.clone(), travel_times(node, &cave));
+    }
+
+    for node in &distances {
+        message!("{:?}", node);
+    }
+
+    Ok((distances, flows))
+}
+
+/// Returns the most pressure that can be released alone in 30 minutes.
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let (distances, flows) = parse(input)?;
+    Ok(pressures(&distances, &flows, 30)
+        .into_values()
+        .max()
+        .unwrap_or(0))
+}
+
+/// Returns the most pressure that can be released with the help of an elephant in 26 minutes.
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    let (distances, flows) = parse(input)?;
+    // Santa and the elephant open different sets of valves, each as well as it can be done alone.
+    let mut pressures: Vec<(u32, u32)> = pressures(&distances, &flows, 26).into_iter().collect();
+    pressures.sort_unstable_by_key(|(_, pressure)| std::cmp::Reverse(*pressure));
+    let mut best = 0;
+    for (index, (mine, pressure)) in pressures.iter().enumerate() {
+        // No pair left can release more than twice the most any of its sets does.
+        if pressure * 2 <= best {
+            break;
+        }
+        if let Some((_, other)) = pressures[index..]
+            .iter()
+            .find(|(elephant, _)| mine & elephant == 0)
+        {
+            best = best.max(pressure + other);
+        }
+    }
+    Ok(best)
+}
+
+#[test]
+fn tests() {
+    use proptest::{
+        prelude::*,
+        test_runner::{Config, TestRunner},
+    };
+
+    let example = include_str!("../example.txt");
+    assert_eq!(1651, part1(example).unwrap());
+    assert_eq!(1707, part2(example).unwrap());
+
+    // The elephant used to open a valve whenever it could, even one Santa needed more.
+    let input = generate::generate(DAY, 12, 14534073947251713507).unwrap();
+    assert_eq!(1579, part2(&input).unwrap());
+
+    // Both searches agree with trying every order of every set of valves.
+    let mut runner = TestRunner::new(Config::with_cases(64));
+    let strategy = (any::<u64>(), 2..=12usize);
+    runner
+        .run(&strategy, |(seed, size)| {
+            let input = generate::generate(DAY, size, seed).unwrap();
+            let (distances, flows) = parse(&input).unwrap();
+            let expected = reference::alone(&distances, &flows, 30);
+            prop_assert_eq!(expected, part1(&input).unwrap(), "{}", input);
+            let expected = reference::with_elephant(&distances, &flows, 26);
+            prop_assert_eq!(expected, part2(&input).unwrap(), "{}", input);
+            Ok(())
+        })
+        .unwrap();
+}
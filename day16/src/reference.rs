@@ -0,0 +1,65 @@
+//! A brute-force search of every order to open the valves in, to check the solver against.
+
+use std::collections::HashMap;
+
+use super::Distances;
+
+/// Returns the most pressure released by opening |valves| in any order from |current|, within
+/// |time_left|.
+fn best_order(
+    valves: &[&str],
+    distances: &Distances,
+    flows: &HashMap<String, u32>,
+    current: &str,
+    time_left: u32,
+) -> u32 {
+    let mut best = 0;
+    for (index, valve) in valves.iter().enumerate() {
+        let cost = distances[current][*valve] + 1;
+        if cost > time_left {
+            continue;
+        }
+        let time_left = time_left - cost;
+        let mut rest = valves.to_vec();
+        rest.remove(index);
+        let pressure =
+            flows[*valve] * time_left + best_order(&rest, distances, flows, valve, time_left);
+        best = best.max(pressure);
+    }
+    best
+}
+
+fn valves(flows: &HashMap<String, u32>) -> Vec<&str> {
+    flows
+        .keys()
+        .filter(|valve| flows[*valve] > 0)
+        .map(String::as_str)
+        .collect()
+}
+
+/// Returns the most pressure that can be released alone in |time_left|.
+pub fn alone(distances: &Distances, flows: &HashMap<String, u32>, time_left: u32) -> u32 {
+    best_order(&valves(flows), distances, flows, "AA", time_left)
+}
+
+/// Returns the most pressure that can be released with the elephant in |time_left|, by trying
+/// every way of sharing the valves between them.
+pub fn with_elephant(distances: &Distances, flows: &HashMap<String, u32>, time_left: u32) -> u32 {
+    let valves = valves(flows);
+    let mut best = 0;
+    for split in 0..1u32 << valves.len() {
+        let (mut mine, mut elephant) = (Vec::new(), Vec::new());
+        for (index, valve) in valves.iter().enumerate() {
+            if split & 1 << index != 0 {
+                mine.push(*valve);
+            } else {
+                elephant.push(*valve);
+            }
+        }
+        best = best.max(
+            best_order(&mine, distances, flows, "AA", time_left)
+                + best_order(&elephant, distances, flows, "AA", time_left),
+        );
+    }
+    best
+}
@@ -0,0 +1,328 @@
+use common::{
+    cycle,
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::Grid,
+};
+
+#[cfg(test)]
+mod reference;
+
+type Field = Grid<bool>;
+
+const DAY: u8 = 17;
+const WIDTH: usize = 7;
+const BLOCKS: usize = 2022;
+//const BLOCKS_PART_2: usize = 100_000_000000;
+const BLOCKS_PART_2: usize = 1_000_000_000_000;
+/// The deepest the surface of the tower is followed, as a shaft nothing falls into could otherwise
+/// make it grow forever. States are only compared while no rock falls deeper than this, so the
+/// rows cut off never decide anything.
+const MAX_DEPTH: usize = 100;
+/// The rows at the top of the tower shown by its frames.
+const VIEW_HEIGHT: usize = 40;
+/// Empty cells, rocks at rest, and the last rock to come to rest.
+const PALETTE: Palette = &[
+    ('.', [15, 15, 25]),
+    ('#', [130, 130, 140]),
+    ('@', [220, 120, 60]),
+];
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct Piece {
+    shape: Vec<u8>,
+    position: usize,
+}
+
+impl Piece {
+    fn from(shape: Vec<u8>, ceiling: usize) -> Piece {
+        Piece {
+            shape,
+            position: 3 + ceiling,
+        }
+    }
+
+    /// Returns the cells covered by the piece once moved by |offset|.
+    fn cells(&self, offset: (isize, isize)) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.shape
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, line)| {
+                (0..WIDTH)
+                    .filter(move |x| line & (1 << (WIDTH - 1 - x)) != 0)
+                    .map(move |x| {
+                        (
+                            x as isize + offset.0,
+                            (index + self.position) as isize + offset.1,
+                        )
+                    })
+            })
+    }
+
+    /// Returns whether the piece, moved by |offset|, stays within the walls and above the floor
+    /// without overlapping the rocks already in |field|.
+    fn fits(&self, field: &Field, offset: (isize, isize)) -> bool {
+        self.cells(offset)
+            .all(|(x, y)| x >= 0 && y >= 0 && field.get((x as usize, y as usize)) == Some(&false))
+    }
+
+    fn can_move_left(&mut self, field: &Field) -> bool {
+        self.fits(field, (-1, 0))
+    }
+
+    fn maybe_move_left(&mut self, field: &Field) -> bool {
+        if !self.can_move_left(field) {
+            return false;
+        }
+        for index in 0..self.shape.len() {
+            self.shape[index] <<= 1;
+        }
+        true
+    }
+
+    fn can_move_right(&mut self, field: &Field) -> bool {
+        self.fits(field, (1, 0))
+    }
+
+    fn maybe_move_right(&mut self, field: &Field) -> bool {
+        if !self.can_move_right(field) {
+            return false;
+        }
+        for index in 0..self.shape.len() {
+            self.shape[index] >>= 1;
+        }
+        true
+    }
+
+    fn maybe_move_down(&mut self, field: &Field) -> bool {
+        if !self.fits(field, (0, -1)) {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    fn paint(&self, field: &mut Field) {
+        let cells: Vec<(isize, isize)> = self.cells((0, 0)).collect();
+        for (x, y) in cells {
+            field[(x as usize, y as usize)] = true;
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct State {
+    surface: Vec<u8>,
+    direction_index: usize,
+    shape_index: usize,
+    /// The number of rocks when one last fell deeper than the surface, which only states reached
+    /// since then share.
+    shallow_since: usize,
+}
+
+/// Returns the cells below |ceiling| that a falling rock can reach, one byte per row from the top,
+/// down to |MAX_DEPTH| rows.
+///
+/// Rocks only move left, right and down, so they never touch anything beyond the reachable cells
+/// and the rocks next to them. As long as no rock falls deeper, the surface decides everything
+/// that happens next: the rows it covers only rise with the ceiling.
+fn surface(field: &Field, ceiling: usize) -> Vec<u8> {
+    let full = (1 << WIDTH) - 1;
+    let mut surface = Vec::new();
+    let mut reachable: u8 = full;
+    for y in (ceiling.saturating_sub(MAX_DEPTH)..ceiling).rev() {
+        let empty = field
+            .row(y)
+            .iter()
+            .fold(0, |line, rock| (line << 1) | !*rock as u8);
+        reachable &= empty;
+        // Spread sideways through the empty cells of the row.
+        loop {
+            let spread = (reachable | reachable << 1 | reachable >> 1) & empty & full;
+            if spread == reachable {
+                break;
+            }
+            reachable = spread;
+        }
+        if reachable == 0 {
+            break;
+        }
+        surface.push(reachable);
+    }
+    surface
+}
+
+/// Returns the jet directions, -1 pushing left and 1 pushing right.
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let source = Source::new(DAY, input);
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(source.missing(jets, "a jet pattern"));
+    }
+    jets.char_indices()
+        .map(|(index, character)| match character {
+            '>' => Ok(1),
+            '<' => Ok(-1),
+            _ => Err(source.char_error(jets, index, "> or <")),
+        })
+        .collect()
+}
+
+const SHAPES: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+/// The chamber after some rocks have fallen.
+struct Tower<'a> {
+    /// The name of the frames of the tower.
+    task: &'a str,
+    directions: &'a [i32],
+    field: Field,
+    /// The number of rocks that came to rest.
+    rocks: usize,
+    /// The number of rocks when one last came to rest deeper than |MAX_DEPTH| rows below the
+    /// ceiling it fell from, or on the floor below them.
+    shallow_since: usize,
+    ceiling: usize,
+    direction_index: usize,
+    shape_index: usize,
+}
+
+impl Tower<'_> {
+    /// Lets the next rock fall until it comes to rest.
+    fn drop_rock(&mut self) {
+        let mut piece = Piece::from(SHAPES[self.shape_index].to_vec(), self.ceiling);
+        self.shape_index = (self.shape_index + 1) % SHAPES.len();
+        if piece.position + 5 > self.field.height() {
+            self.field.resize_height(piece.position + 5, false);
+        }
+        loop {
+            if self.directions[self.direction_index] == -1 {
+                piece.maybe_move_left(&self.field);
+            } else {
+                piece.maybe_move_right(&self.field);
+            }
+            self.direction_index = (self.direction_index + 1) % self.directions.len();
+            if !piece.maybe_move_down(&self.field) {
+                break;
+            }
+        }
+        piece.paint(&mut self.field);
+        self.rocks += 1;
+        // The rock looked at the row below its own before coming to rest.
+        if piece.position + MAX_DEPTH <= self.ceiling {
+            self.shallow_since = self.rocks;
+        }
+        self.ceiling = self.ceiling.max(piece.position + piece.shape.len());
+        frame::record(self.task, PALETTE, || self.draw(&piece));
+    }
+
+    /// Returns the top of the tower, upside up, with |piece| standing out.
+    fn draw(&self, piece: &Piece) -> Grid<char> {
+        let mut view = Grid::new(WIDTH, VIEW_HEIGHT, '.');
+        for row in 0..VIEW_HEIGHT.min(self.ceiling) {
+            for x in 0..WIDTH {
+                if self.field[(x, self.ceiling - 1 - row)] {
+                    view[(x, row)] = '#';
+                }
+            }
+        }
+        for (x, y) in piece.cells((0, 0)) {
+            let row = self.ceiling - 1 - y as usize;
+            if row < VIEW_HEIGHT {
+                view[(x as usize, row)] = '@';
+            }
+        }
+        view
+    }
+
+    fn state(&self) -> State {
+        State {
+            surface: surface(&self.field, self.ceiling),
+            direction_index: self.direction_index,
+            shape_index: self.shape_index,
+            shallow_since: self.shallow_since,
+        }
+    }
+}
+
+/// Returns the height of the tower after |iterations| rocks have fallen.
+fn solve(input: &str, iterations: usize) -> Result<usize, ParseError> {
+    let directions = parse(input)?;
+    let task = format!("Dropping {iterations} rocks");
+    let tower = Tower {
+        task: &task,
+        directions: &directions,
+        field: Grid::new(WIDTH, 0, false),
+        rocks: 0,
+        shallow_since: 0,
+        ceiling: 0,
+        direction_index: 0,
+        shape_index: 0,
+    };
+    let cycle = cycle::detect(iterations, tower, Tower::drop_rock, Tower::state, |tower| {
+        tower.ceiling as i64
+    });
+    Ok(cycle.value(iterations) as usize)
+}
+
+/// Returns the height of the tower after 2022 rocks have fallen.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    solve(input, BLOCKS)
+}
+
+/// Returns the height of the tower after 1000000000000 rocks have fallen.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    solve(input, BLOCKS_PART_2)
+}
+
+#[test]
+fn tests() {
+    use proptest::{
+        prelude::*,
+        test_runner::{Config, TestRunner},
+    };
+
+    let example = include_str!("../example.txt");
+    assert_eq!(3068, part1(example).unwrap());
+    assert_eq!(1514285714288, part2(example).unwrap());
+
+    assert_eq!(3068, reference::height(&parse(example).unwrap(), BLOCKS));
+    // Only the top 20 rows used to be compared, which found a cycle too early.
+    assert_eq!(
+        118,
+        solve("<<><<><><>>><<>>>><<><<<<><<><<<>>>>", 80).unwrap()
+    );
+
+    // A shaft along the wall is followed to the floor, or as deep as rocks are allowed to fall.
+    for (depth, expected) in [(50, 50), (MAX_DEPTH + 1, MAX_DEPTH)] {
+        let mut field = Grid::new(WIDTH, depth, true);
+        for y in 0..depth {
+            field[(0, y)] = false;
+        }
+        assert_eq!(expected, surface(&field, depth).len());
+    }
+    // The two columns along the left wall stay empty down to the floor, yet the tower repeats.
+    let shaft = ">><><";
+    assert_eq!(
+        reference::height(&parse(shaft).unwrap(), 5000),
+        solve(shaft, 5000).unwrap()
+    );
+    assert_eq!(1600000000000, part2(shaft).unwrap());
+
+    // The heights extrapolated from the cycles agree with the ones of every rock simulated.
+    let mut runner = TestRunner::new(Config::with_cases(256));
+    let strategy = (any::<u64>(), 1..=40usize, 0..=3000usize);
+    runner
+        .run(&strategy, |(seed, size, rocks)| {
+            let input = generate::generate(DAY, size, seed).unwrap();
+            let expected = reference::height(&parse(&input).unwrap(), rocks);
+            prop_assert_eq!(expected, solve(&input, rocks).unwrap(), "{}", input);
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,53 @@
+//! A plain simulation of every rock, to check the heights extrapolated from cycles against.
+
+use super::{SHAPES, WIDTH};
+
+/// Returns |line| moved |x| cells to the right, or None if it goes through a wall.
+fn shift(line: u8, x: isize) -> Option<u8> {
+    let moved = if x < 0 {
+        (line as u32) << -x
+    } else {
+        (line as u32) >> x
+    };
+    // Cells moved through the right wall are lost, and those through the left one overflow.
+    (moved < 1 << WIDTH && moved.count_ones() == line.count_ones()).then_some(moved as u8)
+}
+
+/// Returns whether |shape|, moved |x| cells to the right with its bottom on row |y|, overlaps a
+/// wall, the floor or the rocks in |rows|.
+fn collides(rows: &[u8], shape: &[u8], x: isize, y: isize) -> bool {
+    y < 0
+        || shape.iter().enumerate().any(|(index, line)| {
+            let row = rows.get(y as usize + index).copied().unwrap_or(0);
+            shift(*line, x).is_none_or(|line| line & row != 0)
+        })
+}
+
+/// Returns the height of the tower once |rocks| rocks have fallen, pushed by |jets|.
+pub fn height(jets: &[i32], rocks: usize) -> usize {
+    let mut rows: Vec<u8> = Vec::new();
+    let mut jet = 0;
+    for rock in 0..rocks {
+        let shape = SHAPES[rock % SHAPES.len()];
+        let (mut x, mut y) = (0, rows.len() as isize + 3);
+        loop {
+            let pushed = x + jets[jet] as isize;
+            jet = (jet + 1) % jets.len();
+            if !collides(&rows, shape, pushed, y) {
+                x = pushed;
+            }
+            if collides(&rows, shape, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for (index, line) in shape.iter().enumerate() {
+            let row = y as usize + index;
+            if row >= rows.len() {
+                rows.resize(row + 1, 0);
+            }
+            rows[row] |= shift(*line, x).unwrap();
+        }
+    }
+    rows.len()
+}
@@ -0,0 +1,115 @@
+use common::{
+    error::{ParseError, Source},
+    pattern::Pattern,
+    search,
+};
+use std::collections::{HashMap, HashSet};
+
+type Coordinate = (i64, i64, i64);
+
+const DAY: u8 = 18;
+const CUBE: Pattern = Pattern::new("{an x coordinate},{a y coordinate},{a z coordinate}");
+
+fn add(coordinates: &Coordinate, delta: &Coordinate) -> Coordinate {
+    (
+        coordinates.0 + delta.0,
+        coordinates.1 + delta.1,
+        coordinates.2 + delta.2,
+    )
+}
+
+const SIDES: [Coordinate; 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// Returns the air around the cubes, within the box from |bottom_corner| to |top_corner|.
+fn explore_exterior(
+    cubes: &HashMap<Coordinate, i64>,
+    bottom_corner: &Coordinate,
+    top_corner: &Coordinate,
+) -> HashSet<Coordinate> {
+    let inside = |cube: &Coordinate| {
+        cube.0 >= bottom_corner.0
+            && cube.1 >= bottom_corner.1
+            && cube.2 >= bottom_corner.2
+            && cube.0 <= top_corner.0
+            && cube.1 <= top_corner.1
+            && cube.2 <= top_corner.2
+    };
+    let neighbours = |cube: &Coordinate| {
+        let cube = *cube;
+        SIDES
+            .iter()
+            .map(move |side| add(&cube, side))
+            .filter(|next| inside(next) && !cubes.contains_key(next))
+    };
+    search::distances(*bottom_corner, neighbours)
+        .into_keys()
+        .collect()
+}
+
+/// Returns every cube, mapped to the number of its sides that are not touching another cube.
+fn parse(input: &str) -> Result<HashMap<Coordinate, i64>, ParseError> {
+    let source = Source::new(DAY, input);
+    let mut cubes: HashMap<Coordinate, i64> = HashMap::new();
+    for line in input.trim().split('\n') {
+        let mut fields = CUBE.parse(&source, line.trim())?;
+        let coordinates: Coordinate = (fields.parse()?, fields.parse()?, fields.parse()?);
+        let mut number_sides = 6;
+        for side in SIDES {
+            let side = add(&coordinates, &side);
+            if let Some(other_cube) = cubes.get_mut(&side) {
+                *other_cube -= 1;
+                number_sides -= 1;
+            }
+        }
+        cubes.insert(coordinates, number_sides);
+    }
+
+    Ok(cubes)
+}
+
+/// Returns the surface area of the lava droplet.
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    let cubes = parse(input)?;
+    Ok(cubes.values().sum())
+}
+
+/// Returns the exterior surface area of the lava droplet.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let cubes = parse(input)?;
+
+    // Find a cube that envelops the structure.
+    let bottom_corner = cubes.keys().fold((i64::MAX, i64::MAX, i64::MAX), |a, b| {
+        (a.0.min(b.0), a.1.min(b.1), a.2.min(b.2))
+    });
+    let bottom_corner = add(&bottom_corner, &(-1, -1, -1));
+    let top_corner = cubes
+        .keys()
+        .fold((0, 0, 0), |a, b| (a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)));
+    let top_corner = add(&top_corner, &(1, 1, 1));
+
+    let exterior = explore_exterior(&cubes, &bottom_corner, &top_corner);
+
+    Ok(cubes
+        .keys()
+        .map(|cube| {
+            SIDES
+                .iter()
+                .filter(|side| exterior.contains(&add(cube, side)))
+                .count()
+        })
+        .sum())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(64, part1(example).unwrap());
+    assert_eq!(58, part2(example).unwrap());
+}
@@ -0,0 +1,317 @@
+use common::{
+    error::{ParseError, Source},
+    message,
+    pattern::Pattern,
+    progress,
+};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(test)]
+mod reference;
+
+const DAY: u8 = 19;
+const ITERATIONS_PART_1: usize = 24;
+const ITERATIONS_PART_2: usize = 32;
+const BLUEPRINT: Pattern = Pattern::new(
+    "Blueprint {a blueprint number}: \
+     Each ore robot costs {an amount of ore} ore. \
+     Each clay robot costs {an amount of ore} ore. \
+     Each obsidian robot costs {an amount of ore} ore and {an amount of clay} clay. \
+     Each geode robot costs {an amount of ore} ore and {an amount of obsidian} obsidian.",
+);
+
+#[derive(Debug)]
+enum Mineral {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Mineral {
+    fn values() -> [Mineral; 4] {
+        [
+            Mineral::Ore,
+            Mineral::Clay,
+            Mineral::Obsidian,
+            Mineral::Geode,
+        ]
+    }
+}
+
+#[derive(Debug)]
+struct Cost {
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+}
+
+#[derive(Debug)]
+struct Blueprint {
+    ore: Cost,
+    clay: Cost,
+    obsidian: Cost,
+    geode: Cost,
+}
+
+#[derive(Clone)]
+struct Resources {
+    ore: usize,
+    ore_robots: usize,
+
+    clay: usize,
+    clay_robots: usize,
+
+    obsidian: usize,
+    obsidian_robots: usize,
+
+    geode: usize,
+    geode_robots: usize,
+}
+
+impl Resources {
+    fn new() -> Resources {
+        Resources {
+            ore: 0,
+            ore_robots: 1,
+            clay: 0,
+            clay_robots: 0,
+            obsidian: 0,
+            obsidian_robots: 0,
+            geode: 0,
+            geode_robots: 0,
+        }
+    }
+    fn harvest(&mut self) {
+        self.ore += self.ore_robots;
+        self.clay += self.clay_robots;
+        self.obsidian += self.obsidian_robots;
+        self.geode += self.geode_robots;
+    }
+
+    fn spend(&mut self, cost: &Cost) {
+        self.ore -= cost.ore;
+        self.clay -= cost.clay;
+        self.obsidian -= cost.obsidian;
+    }
+
+    fn build_robot(&mut self, target: &Mineral) {
+        match target {
+            Mineral::Ore => self.ore_robots += 1,
+            Mineral::Clay => self.clay_robots += 1,
+            Mineral::Obsidian => self.obsidian_robots += 1,
+            Mineral::Geode => self.geode_robots += 1,
+        }
+    }
+}
+
+impl Blueprint {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Blueprint, ParseError> {
+        let mut fields = BLUEPRINT.parse(source, string.trim())?;
+        // The blueprints are numbered in order.
+        fields.text();
+        Ok(Blueprint {
+            ore: Cost {
+                ore: fields.parse()?,
+                clay: 0,
+                obsidian: 0,
+            },
+            clay: Cost {
+                ore: fields.parse()?,
+                clay: 0,
+                obsidian: 0,
+            },
+            obsidian: Cost {
+                ore: fields.parse()?,
+                clay: fields.parse()?,
+                obsidian: 0,
+            },
+            geode: Cost {
+                ore: fields.parse()?,
+                clay: 0,
+                obsidian: fields.parse()?,
+            },
+        })
+    }
+
+    fn calculate_geodes(&self, iterations: usize) -> usize {
+        let mut best = 0;
+        Mineral::values()
+            .iter()
+            .map(|mineral| self.find_geodes(mineral, iterations, Resources::new(), &mut best))
+            .max()
+            .unwrap()
+    }
+
+    /// Returns whether |robots| of the |target| kind are as many as could ever be used: a robot
+    /// can be built every minute at most, so more than the highest cost in a mineral is never
+    /// spent.
+    fn has_enough(&self, target: &Mineral, resources: &Resources) -> bool {
+        match target {
+            Mineral::Ore => {
+                let costs = [&self.ore, &self.clay, &self.obsidian, &self.geode];
+                resources.ore_robots >= costs.iter().map(|cost| cost.ore).max().unwrap()
+            }
+            Mineral::Clay => resources.clay_robots >= self.obsidian.clay,
+            Mineral::Obsidian => resources.obsidian_robots >= self.geode.obsidian,
+            Mineral::Geode => false,
+        }
+    }
+
+    /// Returns the most geodes opened by building |target| next. |best| holds the most geodes
+    /// found so far.
+    fn find_geodes(
+        &self,
+        target: &Mineral,
+        mut iterations: usize,
+        mut resources: Resources,
+        best: &mut usize,
+    ) -> usize {
+        let cost = match target {
+            Mineral::Ore => &self.ore,
+            Mineral::Clay => &self.clay,
+            Mineral::Obsidian => &self.obsidian,
+            Mineral::Geode => &self.geode,
+        };
+        if cost.clay > 0 && resources.clay_robots == 0
+            || cost.obsidian > 0 && resources.obsidian_robots == 0
+            || self.has_enough(target, &resources)
+        {
+            // We cannot possibly build the target, or have no use for it, return what we have.
+            return iterations * resources.geode_robots + resources.geode;
+        }
+        // Gather the resources to build the target.
+        while resources.ore < cost.ore
+            || resources.clay < cost.clay
+            || resources.obsidian < cost.obsidian
+        {
+            iterations -= 1;
+            resources.harvest();
+
+            if iterations == 0 {
+                // No more iterations remaining.
+                return resources.geode;
+            }
+        }
+
+        // Spend the resources to build the target.
+        resources.spend(cost);
+
+        // Building it takes one iteration.
+        iterations -= 1;
+        resources.harvest();
+
+        if iterations == 0 {
+            // No more iterations remaining.
+            return resources.geode;
+        }
+
+        // The new robot is ready.
+        resources.build_robot(target);
+
+        // Calculate the score for this iteration.
+        let score = iterations * resources.geode_robots + resources.geode;
+        *best = (*best).max(score);
+        // Even a geode robot every iteration left would not beat the best, no point continuing here.
+        if score + iterations * (iterations - 1) / 2 <= *best {
+            return score;
+        }
+
+        let mut max = 0;
+        for target in &Mineral::values() {
+            let geodes = self.find_geodes(target, iterations, resources.clone(), best);
+            if geodes > max {
+                max = geodes;
+            }
+        }
+        max
+    }
+}
+
+fn blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let source = Source::new(DAY, input);
+    input
+        .trim()
+        .split('\n')
+        .map(|line| Blueprint::from(&source, line))
+        .collect()
+}
+
+/// Returns the sum of the quality levels of every blueprint.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let blueprints = blueprints(input)?;
+    // The blueprints are calculated in parallel, so they finish in any order.
+    let done = AtomicUsize::new(0);
+    Ok(blueprints
+        .par_iter()
+        .enumerate()
+        .map(|(index, blueprint)| {
+            let score = (index + 1) * blueprint.calculate_geodes(ITERATIONS_PART_1);
+            let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+            progress::progress("Calculating blueprints", done, blueprints.len());
+            message!("The score is {score}");
+            score
+        })
+        .sum())
+}
+
+/// Returns the product of the geodes opened by the first three blueprints in 32 minutes.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let blueprints = blueprints(input)?;
+    let done = AtomicUsize::new(0);
+    Ok(blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| {
+            let geodes = blueprint.calculate_geodes(ITERATIONS_PART_2);
+            let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+            progress::progress("Calculating blueprints", done, blueprints.len().min(3));
+            message!("The number of geodes is {geodes}");
+            geodes
+        })
+        .product())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(33, part1(example).unwrap());
+    assert_eq!(3472, part2(example).unwrap());
+
+    // The best scores of earlier minutes used to cut off the search, missing a geode here.
+    let blueprint = &blueprints(&generate::generate(DAY, 1, 4).unwrap()).unwrap()[0];
+    assert_eq!(20, blueprint.calculate_geodes(ITERATIONS_PART_2));
+
+    // The pruned search agrees with the exhaustive one, up to the time of the first part.
+    cross_check(64, 10..=ITERATIONS_PART_1);
+}
+
+/// Checks the pruned search against the exhaustive one on |cases| random blueprints, for a random
+/// number of |minutes|.
+#[cfg(test)]
+fn cross_check(cases: u32, minutes: std::ops::RangeInclusive<usize>) {
+    use proptest::{
+        prelude::*,
+        test_runner::{Config, TestRunner},
+    };
+
+    let mut runner = TestRunner::new(Config::with_cases(cases));
+    runner
+        .run(&(any::<u64>(), minutes), |(seed, minutes)| {
+            let input = generate::generate(DAY, 1, seed).unwrap();
+            let blueprint = &blueprints(&input).unwrap()[0];
+            let expected = reference::geodes(blueprint, minutes);
+            prop_assert_eq!(expected, blueprint.calculate_geodes(minutes), "{}", input);
+            Ok(())
+        })
+        .unwrap();
+}
+
+/// Runs the cross-check up to the time of the second part, which takes a while without
+/// optimisations: `cargo test --release -p day19 -- --ignored`.
+#[test]
+#[ignore]
+fn cross_check_long() {
+    cross_check(256, ITERATIONS_PART_1..=ITERATIONS_PART_2);
+}
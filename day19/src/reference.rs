@@ -0,0 +1,82 @@
+//! A search of every choice of robot, minute after minute, to check the pruned search against. It
+//! only leaves out choices that can be shown to never open more geodes.
+
+use super::{Blueprint, Cost};
+
+/// The robots or the minerals of each kind, as ore, clay, obsidian and geode.
+type Counts = [usize; 4];
+
+struct Search<'a> {
+    costs: [&'a Cost; 4],
+    /// The most robots of each kind worth having. A robot can be built every minute at most, so
+    /// more than the highest cost in a mineral is never spent.
+    caps: Counts,
+    /// The most geodes opened so far.
+    best: usize,
+}
+
+impl Search<'_> {
+    fn affords(&self, robot: usize, minerals: &Counts) -> bool {
+        let cost = self.costs[robot];
+        minerals[0] >= cost.ore && minerals[1] >= cost.clay && minerals[2] >= cost.obsidian
+    }
+
+    /// Tries every choice from now on. |skipped| holds the robots that could have been built since
+    /// the last one was, and are no better built later.
+    fn run(&mut self, minutes: usize, robots: Counts, minerals: Counts, mut skipped: [bool; 4]) {
+        if minutes == 0 {
+            self.best = self.best.max(minerals[3]);
+            return;
+        }
+        // Even a geode robot every minute left would not beat the best.
+        if minerals[3] + robots[3] * minutes + minutes * (minutes - 1) / 2 <= self.best {
+            return;
+        }
+        let mut harvested = minerals;
+        for (mineral, robots) in harvested.iter_mut().zip(robots) {
+            *mineral += robots;
+        }
+        for robot in (0..4).rev() {
+            if skipped[robot]
+                || robots[robot] >= self.caps[robot]
+                || !self.affords(robot, &minerals)
+            {
+                continue;
+            }
+            let cost = self.costs[robot];
+            let mut built = robots;
+            built[robot] += 1;
+            let mut left = harvested;
+            left[0] -= cost.ore;
+            left[1] -= cost.clay;
+            left[2] -= cost.obsidian;
+            self.run(minutes - 1, built, left, [false; 4]);
+        }
+        for (robot, skipped) in skipped.iter_mut().enumerate() {
+            *skipped |= self.affords(robot, &minerals);
+        }
+        self.run(minutes - 1, robots, harvested, skipped);
+    }
+}
+
+/// Returns the most geodes |blueprint| can open in |minutes|.
+pub fn geodes(blueprint: &Blueprint, minutes: usize) -> usize {
+    let costs = [
+        &blueprint.ore,
+        &blueprint.clay,
+        &blueprint.obsidian,
+        &blueprint.geode,
+    ];
+    let mut search = Search {
+        costs,
+        caps: [
+            costs.iter().map(|cost| cost.ore).max().unwrap(),
+            blueprint.obsidian.clay,
+            blueprint.geode.obsidian,
+            usize::MAX,
+        ],
+        best: 0,
+    };
+    search.run(minutes, [1, 0, 0, 0], [0; 4], [false; 4]);
+    search.best
+}
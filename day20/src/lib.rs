@@ -0,0 +1,83 @@
+use common::error::{ParseError, Source};
+
+const DAY: u8 = 20;
+
+fn mix(numbers: &mut Vec<(usize, i64)>) {
+    for i in 0..numbers.len() {
+        let (index, &number) = numbers
+            .iter()
+            .enumerate()
+            .find(|(_, (index, _))| *index == i)
+            .unwrap();
+        let mut new_index = index as i64 + number.1;
+        new_index %= numbers.len() as i64 - 1;
+        if new_index < 0 {
+            new_index += numbers.len() as i64 - 1;
+        }
+        let new_index = new_index as usize;
+
+        numbers.remove(index);
+        numbers.insert(new_index, number);
+    }
+}
+
+fn grove_coordinates(numbers: &[(usize, i64)]) -> i64 {
+    let (zero_index, _) = numbers
+        .iter()
+        .enumerate()
+        .find(|(_, (_, number))| *number == 0)
+        .unwrap();
+    numbers[(zero_index + 1000) % numbers.len()].1
+        + numbers[(zero_index + 2000) % numbers.len()].1
+        + numbers[(zero_index + 3000) % numbers.len()].1
+}
+
+/// Returns the numbers along with their original index, checking that there are at least two to
+/// mix and that one of them is zero.
+fn parse(input: &str) -> Result<Vec<(usize, i64)>, ParseError> {
+    let source = Source::new(DAY, input);
+    let numbers = input
+        .trim()
+        .split('\n')
+        .map(|line| source.parse(line.trim(), "a number"))
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+    if numbers.len() < 2 {
+        return Err(source.missing(input, "at least two numbers"));
+    }
+    if !numbers.contains(&0) {
+        return Err(source.missing(input, "a zero"));
+    }
+    Ok(numbers.into_iter().enumerate().collect())
+}
+
+/// Returns the sum of the grove coordinates after mixing once.
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    let mut numbers = parse(input)?;
+    mix(&mut numbers);
+    Ok(grove_coordinates(&numbers))
+}
+
+/// Returns the sum of the grove coordinates after applying the decryption key and mixing ten times.
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    let mut numbers = parse(input)?;
+    for number in numbers.iter_mut() {
+        number.1 *= 811589153;
+    }
+    for _ in 0..10 {
+        mix(&mut numbers);
+    }
+    Ok(grove_coordinates(&numbers))
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(3, part1(example).unwrap());
+    assert_eq!(1623178306, part2(example).unwrap());
+
+    assert_eq!(
+        "Day 20, line 2, column 1: expected at least two numbers, found nothing",
+        part1("0\n").unwrap_err().to_string()
+    );
+    assert_eq!(0, part2("0\n1").unwrap());
+}
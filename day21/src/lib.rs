@@ -0,0 +1,292 @@
+use common::{
+    error::{ParseError, Source},
+    pattern::Pattern,
+};
+use std::collections::HashMap;
+
+const DAY: u8 = 21;
+const MONKEY: Pattern = Pattern::new("{a monkey}: {an expression}");
+/// A monkey either yells the result of an operation on the numbers of two others, or a number.
+const EXPRESSIONS: [Pattern; 2] = [
+    Pattern::new("{a monkey} {an operation} {a monkey}"),
+    Pattern::new("{a number}"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operation {
+    Add,
+    Sub,
+    Multiply,
+    Divide,
+    Equality,
+}
+
+impl Operation {
+    fn from(source: &Source, string: &str) -> Result<Operation, ParseError> {
+        match string {
+            "+" => Ok(Operation::Add),
+            "-" => Ok(Operation::Sub),
+            "*" => Ok(Operation::Multiply),
+            "/" => Ok(Operation::Divide),
+            _ => Err(source.error(string, "+, -, * or /")),
+        }
+    }
+
+    fn operate(&self, a: f64, b: f64) -> f64 {
+        match &self {
+            Operation::Add => a + b,
+            Operation::Sub => a - b,
+            Operation::Multiply => a * b,
+            Operation::Divide => a / b,
+            Operation::Equality => panic!("Tried to operate on an equality"),
+        }
+    }
+
+    fn invert(&self) -> Operation {
+        match &self {
+            Operation::Add => Operation::Sub,
+            Operation::Sub => Operation::Add,
+            Operation::Multiply => Operation::Divide,
+            Operation::Divide => Operation::Multiply,
+            Operation::Equality => panic!("Tried to invert an equality"),
+        }
+    }
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Operation::Add => '+',
+            Operation::Sub => '-',
+            Operation::Multiply => '*',
+            Operation::Divide => '/',
+            Operation::Equality => '=',
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Expression {
+    Constant(f64),
+    Expression(String, Operation, String),
+}
+
+impl Expression {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Expression, ParseError> {
+        match Pattern::parse_any(&EXPRESSIONS, source, string)? {
+            (0, mut fields) => Ok(Expression::Expression(
+                fields.text().to_string(),
+                Operation::from(source, fields.text())?,
+                fields.text().to_string(),
+            )),
+            (_, mut fields) => Ok(Expression::Constant(fields.parse()?)),
+        }
+    }
+
+    fn invert(&self) -> Operation {
+        match &self {
+            Expression::Expression(_, operation, _) => operation.invert(),
+            _ => panic!("Attempted to invert a constant"),
+        }
+    }
+
+    fn operation(&self) -> Operation {
+        match &self {
+            Expression::Expression(_, operation, _) => operation.clone(),
+            _ => panic!("Attempted to invert a constant"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Branch {
+    expression: Expression,
+    has_variable: bool,
+}
+
+impl Branch {
+    fn from(expression: Expression) -> Branch {
+        Branch {
+            expression,
+            has_variable: false,
+        }
+    }
+}
+
+fn solve(root: &str, expressions: &HashMap<String, Expression>) -> f64 {
+    match &expressions[root] {
+        Expression::Constant(number) => *number,
+        Expression::Expression(a, operation, b) => {
+            operation.operate(solve(a, expressions), solve(b, expressions))
+        }
+    }
+}
+
+fn find_x(root: &str, expressions: &mut HashMap<String, Branch>) {
+    if root == "humn" {
+        expressions.get_mut(root).unwrap().has_variable = true;
+        return;
+    }
+    let expression = &expressions.get(root).unwrap().expression.clone();
+    if let Expression::Expression(a, _, b) = expression {
+        find_x(a, expressions);
+        find_x(b, expressions);
+        assert!(!(expressions[a].has_variable & expressions[b].has_variable));
+        if expressions[a].has_variable || expressions[b].has_variable {
+            expressions.get_mut(root).unwrap().has_variable = true;
+        }
+    }
+}
+
+fn clear_x(root: &str, expressions: &mut HashMap<String, Branch>) {
+    assert!(expressions[root].has_variable);
+    loop {
+        let (root_left_tag, root_right_tag) =
+            if let Expression::Expression(left, _, right) = &expressions[root].expression {
+                (left, right)
+            } else {
+                panic!("Unexpected constant on root");
+            };
+
+        let mut root_left = &expressions[root_left_tag];
+        let root_right = &expressions[root_right_tag];
+
+        // Always have the side with the variable on the "left".
+        if root_right.has_variable {
+            root_left = root_right;
+        }
+        if let Expression::Constant(_) = root_left.expression {
+            return;
+        }
+
+        let (left_left_tag, left_right_tag) = if let Expression::Expression(left, _, right) =
+            &expressions[root_left_tag].expression
+        {
+            (left, right)
+        } else {
+            panic!("Unexpected constant on root");
+        };
+
+        let left_left = &expressions[left_left_tag];
+        let left_right = &expressions[left_right_tag];
+        let passed_tag = root_left_tag;
+        let (passed, new_root) = if left_right.has_variable {
+            let operation = root_left.expression.operation();
+            let passed = if operation == Operation::Divide || operation == Operation::Sub {
+                Expression::Expression(
+                    left_left_tag.clone(),
+                    operation.clone(),
+                    root_right_tag.clone(),
+                )
+            } else {
+                Expression::Expression(
+                    root_right_tag.clone(),
+                    operation.invert(),
+                    left_left_tag.clone(),
+                )
+            };
+            let new_root = Expression::Expression(
+                left_right_tag.clone(),
+                Operation::Equality,
+                passed_tag.clone(),
+            );
+            (passed, new_root)
+        } else {
+            assert!(left_left.has_variable);
+            let passed = Expression::Expression(
+                root_right_tag.clone(),
+                root_left.expression.invert(),
+                left_right_tag.clone(),
+            );
+            let new_root = Expression::Expression(
+                left_left_tag.clone(),
+                Operation::Equality,
+                passed_tag.clone(),
+            );
+            (passed, new_root)
+        };
+        expressions.insert(passed_tag.clone(), Branch::from(passed));
+        expressions.insert(root.to_string(), Branch::from(new_root));
+    }
+}
+
+/// Returns the expression of every monkey, checking that the monkeys they wait for exist.
+fn parse(input: &str) -> Result<HashMap<String, Expression>, ParseError> {
+    let source = Source::new(DAY, input);
+    let mut expressions = HashMap::new();
+    let mut operands: Vec<&str> = Vec::new();
+    for line in input.trim().split('\n') {
+        let mut fields = MONKEY.parse(&source, line.trim())?;
+        let monkey = fields.text();
+        let string = fields.text();
+        let expression = Expression::from(&source, string)?;
+        if let Expression::Expression(..) = expression {
+            // Skip the operation between the two monkeys.
+            operands.extend(string.split_whitespace().step_by(2));
+        }
+        expressions.insert(monkey.to_string(), expression);
+    }
+    for monkey in ["root", "humn"] {
+        if !expressions.contains_key(monkey) {
+            return Err(source.missing(input, format!("a monkey named {monkey}")));
+        }
+    }
+    if let Some(operand) = operands
+        .iter()
+        .find(|operand| !expressions.contains_key(**operand))
+    {
+        return Err(source.error(operand, "a monkey that exists"));
+    }
+    Ok(expressions)
+}
+
+/// Returns the number yelled by the root monkey.
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    let expressions = parse(input)?;
+    Ok(solve("root", &expressions).round() as i64)
+}
+
+/// Returns the number to yell so that both sides of the root monkey's equation are equal.
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    let expressions = parse(input)?;
+    let mut expressions: HashMap<String, Branch> = expressions
+        .iter()
+        .map(|(key, value)| (key.clone(), Branch::from(value.clone())))
+        .collect();
+    if let Expression::Expression(a, _, b) = &expressions["root"].expression {
+        expressions.insert(
+            "root".to_string(),
+            Branch::from(Expression::Expression(
+                a.to_string(),
+                Operation::Equality,
+                b.to_string(),
+            )),
+        );
+    }
+    find_x("root", &mut expressions);
+    clear_x("root", &mut expressions);
+
+    let expressions: HashMap<String, Expression> = expressions
+        .iter()
+        .map(|(key, value)| (key.clone(), value.expression.clone()))
+        .collect();
+    if let Expression::Expression(left, _, right) = &expressions["root"] {
+        let result = if left == "humn" {
+            solve(right, &expressions)
+        } else {
+            assert!(right == "humn");
+            solve(left, &expressions)
+        };
+        Ok(result.round() as i64)
+    } else {
+        panic!("Did not properly clear X");
+    }
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(152, part1(example).unwrap());
+    assert_eq!(301, part2(example).unwrap());
+}
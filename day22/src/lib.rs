@@ -0,0 +1,283 @@
+use common::{
+    error::{ParseError, Source},
+    message,
+};
+use std::fmt::Display;
+
+const DAY: u8 = 22;
+
+#[derive(Debug)]
+struct Row {
+    shift: i32,
+    path: Vec<bool>,
+}
+
+impl Row {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Row, ParseError> {
+        let tiles = string.trim();
+        if tiles.is_empty() {
+            return Err(source.missing(string, "a row of tiles"));
+        }
+        let shift = (string.len() - string.trim_start().len()) as i32;
+        let path = tiles
+            .char_indices()
+            .map(|(index, tile)| match tile {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(source.char_error(tiles, index, "# or .")),
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Row { shift, path })
+    }
+
+    fn len(&self) -> i32 {
+        self.path.len() as i32 + self.shift
+    }
+
+    fn is_occupied(&self, x: i32) -> bool {
+        assert!(x >= self.shift && x < self.len());
+        self.path[(x - self.shift) as usize]
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    rows: Vec<Row>,
+}
+
+impl Map {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Map, ParseError> {
+        Ok(Map {
+            rows: string
+                .split('\n')
+                .map(|row| Row::from(source, row))
+                .collect::<Result<_, ParseError>>()?,
+        })
+    }
+
+    fn starting_position(&self) -> Position {
+        let leftmost_tile = self.rows[0]
+            .path
+            .iter()
+            .enumerate()
+            .find(|(_, &occupied)| !occupied)
+            .expect("Could not find open tile")
+            .0;
+        Position {
+            x: leftmost_tile as i32 + self.rows[0].shift,
+            y: 0,
+            facing: Direction::Right,
+        }
+    }
+
+    fn walk(&self, path: &Path, mut position: Position) -> Position {
+        for instruction in &path.instructions {
+            match instruction {
+                Instruction::Left => position.turn_left(),
+                Instruction::Right => position.turn_right(),
+                Instruction::Forward(steps) => {
+                    for _ in 0..*steps {
+                        let mut next = position.peek();
+                        next = self.maybe_wrap(next);
+                        if self.is_occupied(&next) {
+                            break;
+                        }
+                        position = next;
+                    }
+                }
+            }
+        }
+        position
+    }
+
+    fn is_occupied(&self, position: &Position) -> bool {
+        self.rows[position.y as usize].is_occupied(position.x)
+    }
+
+    fn maybe_wrap(&self, mut position: Position) -> Position {
+        if position.y < 0
+            || position.y >= self.rows.len() as i32
+            || position.x < self.rows[position.y as usize].shift
+            || position.x >= self.rows[position.y as usize].len()
+        {
+            // First, wrap the cursor.
+            match position.facing {
+                Direction::Up => position.y = self.rows.len() as i32 - 1,
+                Direction::Down => position.y = 0,
+                Direction::Left => position.x = self.rows[position.y as usize].len() - 1,
+                Direction::Right => position.x = self.rows[position.y as usize].shift,
+            }
+            // Then, walk until we find land.
+            while position.x < self.rows[position.y as usize].shift
+                || position.x >= self.rows[position.y as usize].len()
+            {
+                position.step();
+            }
+        }
+        position
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Forward(i32),
+    Left,
+    Right,
+}
+
+impl Instruction {
+    fn from(source: &Source, string: &str) -> Result<Instruction, ParseError> {
+        match string {
+            "L" => Ok(Instruction::Left),
+            "R" => Ok(Instruction::Right),
+            number => Ok(Instruction::Forward(
+                source.parse(number, "L, R or a number")?,
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Path {
+    instructions: Vec<Instruction>,
+}
+
+impl Path {
+    fn from<'a>(source: &Source<'a>, string: &'a str) -> Result<Path, ParseError> {
+        let mut instructions = Vec::new();
+        let string = string.trim();
+        // The start of the number being read, if any.
+        let mut number = None;
+        for (index, next) in string.char_indices() {
+            if next.is_ascii_digit() {
+                number.get_or_insert(index);
+            } else {
+                if let Some(start) = number.take() {
+                    instructions.push(Instruction::from(source, &string[start..index])?);
+                }
+                let turn = &string[index..index + next.len_utf8()];
+                instructions.push(Instruction::from(source, turn)?);
+            }
+        }
+        if let Some(start) = number {
+            instructions.push(Instruction::from(source, &string[start..])?);
+        }
+        Ok(Path { instructions })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn value(&self) -> i32 {
+        match self {
+            Direction::Up => 3,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 0,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Up => f.write_str("Up"),
+            Direction::Down => f.write_str("Down"),
+            Direction::Left => f.write_str("Left"),
+            Direction::Right => f.write_str("Right"),
+        }
+        .unwrap();
+        f.write_fmt(format_args!(" ({})", self.value()))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+    facing: Direction,
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Row: {}, Column: {}, Direction: {}",
+            self.y + 1,
+            self.x + 1,
+            self.facing
+        ))
+    }
+}
+
+impl Position {
+    fn turn_left(&mut self) {
+        self.facing = match self.facing {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    fn turn_right(&mut self) {
+        self.facing = match self.facing {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+
+    fn peek(&self) -> Position {
+        let mut x = self.x;
+        let mut y = self.y;
+        match self.facing {
+            Direction::Up => y -= 1,
+            Direction::Down => y += 1,
+            Direction::Left => x -= 1,
+            Direction::Right => x += 1,
+        };
+        Position {
+            x,
+            y,
+            facing: self.facing,
+        }
+    }
+
+    fn step(&mut self) {
+        *self = self.peek();
+    }
+
+    fn to_result(&self) -> i32 {
+        (self.y + 1) * 1000 + (self.x + 1) * 4 + self.facing.value()
+    }
+}
+
+/// Returns the final password after walking the path on the flat map.
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    let source = Source::new(DAY, input);
+    let mut parts = input.split("\n\n");
+    let map = Map::from(&source, source.next(&mut parts, input, "a map")?)?;
+    let path = Path::from(&source, source.next(&mut parts, input, "a path")?)?;
+
+    let starting_position = map.starting_position();
+    message!("Starting position: {}", starting_position);
+
+    let finish_position = map.walk(&path, starting_position.clone());
+    message!("Finish position: {}", &finish_position);
+
+    Ok(finish_position.to_result())
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(6032, part1(example).unwrap());
+}
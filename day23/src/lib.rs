@@ -0,0 +1,173 @@
+use common::{
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::Grid,
+};
+use std::collections::{HashMap, HashSet};
+
+type Coordinate = (i32, i32);
+
+const DAY: u8 = 23;
+const ROUNDS: usize = 10;
+const PALETTE: Palette = &[('.', [30, 60, 30]), ('#', [200, 60, 50])];
+
+#[derive(Debug)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    fn is_clear(&self, elf: &Coordinate, map: &HashSet<Coordinate>) -> bool {
+        let to_check = match &self {
+            Direction::North => [(-1, -1), (0, -1), (1, -1)],
+            Direction::South => [(-1, 1), (0, 1), (1, 1)],
+            Direction::West => [(-1, -1), (-1, 0), (-1, 1)],
+            Direction::East => [(1, -1), (1, 0), (1, 1)],
+        };
+        for direction in to_check {
+            if map.contains(&(elf.0 + direction.0, elf.1 + direction.1)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn shift(&self, elf: &Coordinate) -> Coordinate {
+        let delta = match &self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+            Direction::East => (1, 0),
+        };
+        (delta.0 + elf.0, delta.1 + elf.1)
+    }
+}
+
+fn parse(input: &str) -> Result<HashSet<Coordinate>, ParseError> {
+    let source = Source::new(DAY, input);
+    let grid = Grid::parse(&source, input, "# or .", |tile| match tile {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let elves: HashSet<Coordinate> = grid
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect();
+    if elves.is_empty() {
+        return Err(source.missing(input, "an elf"));
+    }
+    Ok(elves)
+}
+
+fn directions() -> Vec<Direction> {
+    vec![
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ]
+}
+
+/// Returns the smallest rectangle containing every elf.
+fn to_grid(map: &HashSet<Coordinate>) -> Grid<bool> {
+    let min_x = map.iter().map(|elf| elf.0).min().unwrap();
+    let max_x = map.iter().map(|elf| elf.0).max().unwrap();
+    let min_y = map.iter().map(|elf| elf.1).min().unwrap();
+    let max_y = map.iter().map(|elf| elf.1).max().unwrap();
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut grid = Grid::new(width, height, false);
+    for elf in map {
+        grid[((elf.0 - min_x) as usize, (elf.1 - min_y) as usize)] = true;
+    }
+    grid
+}
+
+/// Records the elves of |map| as a frame of |task|.
+fn record(task: &str, map: &HashSet<Coordinate>) {
+    frame::record(task, PALETTE, || {
+        to_grid(map).map(|elf| if *elf { '#' } else { '.' })
+    });
+}
+
+/// Moves the elves for a single round. Returns None if no elf had to move.
+fn round(map: &HashSet<Coordinate>, directions: &mut [Direction]) -> Option<HashSet<Coordinate>> {
+    // False means a single elf proposed moving to the coordinate, true otherwise.
+    let mut all_elves_clear = true;
+    let mut proposed_coordinates: HashMap<Coordinate, bool> = HashMap::new();
+    let mut proposed_elf_position: HashMap<Coordinate, Coordinate> = HashMap::new();
+    for elf in map {
+        let all_empty = directions
+            .iter()
+            .all(|direction| direction.is_clear(elf, map));
+        if all_empty {
+            continue;
+        }
+        all_elves_clear = false;
+        for direction in directions.iter() {
+            if direction.is_clear(elf, map) {
+                let proposed = direction.shift(elf);
+                let occupied = proposed_coordinates.contains_key(&proposed);
+                proposed_coordinates.insert(proposed, occupied);
+                proposed_elf_position.insert(*elf, proposed);
+                break;
+            }
+        }
+    }
+
+    if all_elves_clear {
+        return None;
+    }
+
+    let mut new_map: HashSet<Coordinate> = HashSet::new();
+    for elf in map {
+        match proposed_elf_position.get(elf) {
+            Some(proposed) if !proposed_coordinates[proposed] => new_map.insert(*proposed),
+            _ => new_map.insert(*elf),
+        };
+    }
+    directions.rotate_left(1);
+    Some(new_map)
+}
+
+/// Returns the number of empty tiles in the rectangle containing every elf after ten rounds.
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let mut map = parse(input)?;
+    let mut directions = directions();
+    record("Spreading out for ten rounds", &map);
+    for _ in 0..ROUNDS {
+        if let Some(new_map) = round(&map, &mut directions) {
+            map = new_map;
+        }
+        record("Spreading out for ten rounds", &map);
+    }
+
+    let grid = to_grid(&map);
+    Ok(grid.iter().filter(|(_, elf)| !**elf).count() as u32)
+}
+
+/// Returns the first round in which no elf moves.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let mut map = parse(input)?;
+    let mut directions = directions();
+    let mut round_number = 1;
+    record("Spreading out until no elf moves", &map);
+    while let Some(new_map) = round(&map, &mut directions) {
+        map = new_map;
+        round_number += 1;
+        record("Spreading out until no elf moves", &map);
+    }
+    Ok(round_number)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(110, part1(example).unwrap());
+    assert_eq!(20, part2(example).unwrap());
+}
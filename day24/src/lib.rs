@@ -0,0 +1,286 @@
+use common::{
+    error::{ParseError, Source},
+    frame::{self, Palette},
+    grid::{Grid, Point},
+    search,
+};
+use std::{collections::HashSet, iter};
+
+type Coordinate = Point;
+
+const DAY: u8 = 24;
+/// The valley, its walls, its blizzards alone or meeting, and the expedition.
+const PALETTE: Palette = &[
+    ('.', [20, 20, 35]),
+    ('#', [110, 110, 120]),
+    ('^', [90, 150, 230]),
+    ('v', [90, 150, 230]),
+    ('>', [90, 150, 230]),
+    ('<', [90, 150, 230]),
+    ('2', [150, 200, 250]),
+    ('3', [190, 225, 255]),
+    ('4', [230, 245, 255]),
+    ('E', [80, 220, 100]),
+];
+
+#[derive(Clone, PartialEq)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn from(character: char) -> Option<Direction> {
+        match character {
+            '^' => Some(Direction::North),
+            'v' => Some(Direction::South),
+            '>' => Some(Direction::East),
+            '<' => Some(Direction::West),
+            '.' => None,
+            '#' => None,
+            _ => unreachable!("Characters are checked while parsing"),
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::South => 'v',
+            Direction::East => '>',
+            Direction::West => '<',
+        }
+    }
+
+    fn shift(&self, direction: Coordinate) -> Coordinate {
+        match self {
+            Direction::North => (direction.0, direction.1 - 1),
+            Direction::South => (direction.0, direction.1 + 1),
+            Direction::East => (direction.0 + 1, direction.1),
+            Direction::West => (direction.0 - 1, direction.1),
+        }
+    }
+}
+
+struct Blizzard {
+    direction: Direction,
+    position: Coordinate,
+}
+
+impl Blizzard {
+    fn from(direction: char, position: Coordinate) -> Option<Blizzard> {
+        Direction::from(direction).map(|direction| Blizzard {
+            direction,
+            position,
+        })
+    }
+
+    fn shift(&self) -> Coordinate {
+        self.direction.shift(self.position)
+    }
+}
+
+struct Map {
+    blizzards: Vec<Blizzard>,
+    /// The walls around the valley, which never move.
+    walls: Grid<bool>,
+    /// The tiles taken by either a wall or a blizzard.
+    occupied: Grid<bool>,
+}
+
+impl Map {
+    fn from(string: &str) -> Result<Map, ParseError> {
+        let source = Source::new(DAY, string);
+        let tiles = Grid::parse(&source, string, "a blizzard, . or #", |tile| {
+            matches!(tile, '^' | 'v' | '>' | '<' | '.' | '#').then_some(tile)
+        })?;
+        let mut map = Map {
+            blizzards: tiles
+                .iter()
+                .filter_map(|(position, tile)| Blizzard::from(*tile, position))
+                .collect(),
+            walls: tiles.map(|tile| *tile == '#'),
+            occupied: Grid::default(),
+        };
+        map.calculate_occupied();
+        Ok(map)
+    }
+
+    fn width(&self) -> usize {
+        self.walls.width()
+    }
+
+    fn height(&self) -> usize {
+        self.walls.height()
+    }
+
+    fn calculate_occupied(&mut self) {
+        self.occupied = self.walls.clone();
+        for blizzard in &self.blizzards {
+            self.occupied[blizzard.position] = true;
+        }
+    }
+
+    fn step(&self) -> Map {
+        let mut other = Map {
+            blizzards: Vec::with_capacity(self.blizzards.len()),
+            walls: self.walls.clone(),
+            occupied: Grid::default(),
+        };
+        for blizzard in &self.blizzards {
+            let mut position = blizzard.shift();
+            if position.0 >= self.width() - 1 {
+                position.0 = 1;
+            }
+            if position.0 == 0 {
+                position.0 = self.width() - 2;
+            }
+            if position.1 >= self.height() - 1 {
+                position.1 = 1;
+            }
+            if position.1 == 0 {
+                position.1 = self.height() - 2;
+            }
+            other.blizzards.push(Blizzard {
+                position,
+                direction: blizzard.direction.clone(),
+            });
+        }
+        other.calculate_occupied();
+        other
+    }
+
+    /// Returns the valley with the number of blizzards wherever several of them meet, and the
+    /// expedition at every position of |expedition|.
+    fn draw(&self, expedition: &HashSet<Coordinate>) -> Grid<char> {
+        let mut view = self.walls.map(|wall| if *wall { '#' } else { '.' });
+        for blizzard in &self.blizzards {
+            let cell = &mut view[blizzard.position];
+            *cell = match *cell {
+                '.' => blizzard.direction.symbol(),
+                count if count.is_ascii_digit() => (count as u8 + 1) as char,
+                _ => '2',
+            };
+        }
+        for position in expedition {
+            view[*position] = 'E';
+        }
+        view
+    }
+
+    fn is_valid(&self, position: Coordinate) -> bool {
+        self.occupied.get(position) == Some(&false)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Step {
+    position: Coordinate,
+    step: usize,
+}
+
+/// Records the valley of every minute of |maps| as a frame of |task|, along with every position
+/// the expedition could have reached from |start| by then.
+fn record(task: &str, maps: &[Map], start: Coordinate) {
+    if !frame::is_recording() {
+        return;
+    }
+    let mut reached = HashSet::from([start]);
+    for (minute, map) in maps.iter().enumerate() {
+        if minute > 0 {
+            reached = reached
+                .iter()
+                .flat_map(|position| {
+                    iter::once(*position).chain(map.occupied.neighbours4(*position))
+                })
+                .filter(|position| map.is_valid(*position))
+                .collect();
+        }
+        frame::record(task, PALETTE, || map.draw(&reached));
+    }
+}
+
+/// Returns the fewest minutes to go from |initial_position| to |final_position| through |map|,
+/// along with the valley by then. The search is recorded as the frames of |task|.
+fn find_shortest_path(
+    task: &str,
+    initial_position: Coordinate,
+    final_position: Coordinate,
+    map: Map,
+) -> (usize, Map) {
+    // The valley after every minute, computed as the search reaches it.
+    let mut maps = vec![map];
+    let successors = |current: &Step| {
+        if maps.len() == current.step + 1 {
+            let next_map = maps[current.step].step();
+            maps.push(next_map);
+        }
+        let map = &maps[current.step + 1];
+        // Either stay in place or move to a neighbour.
+        iter::once(current.position)
+            .chain(map.occupied.neighbours4(current.position))
+            .filter(|position| map.is_valid(*position))
+            .map(|position| Step {
+                position,
+                step: current.step + 1,
+            })
+            .collect::<Vec<Step>>()
+    };
+    let initial_step = Step {
+        position: initial_position,
+        step: 0,
+    };
+    let (minutes, _) = search::bfs(initial_step, successors, |step| {
+        step.position == final_position
+    })
+    .expect("Could not find a path through the blizzards");
+    record(task, &maps[..=minutes], initial_position);
+    (minutes, maps.swap_remove(minutes))
+}
+
+/// Returns the fewest minutes required to reach the goal.
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let map = Map::from(input)?;
+    let initial_position = (1, 0);
+    let final_position = (map.width() - 2, map.height() - 1);
+
+    let (first_path, _) =
+        find_shortest_path("Crossing the valley", initial_position, final_position, map);
+    Ok(first_path)
+}
+
+/// Returns the fewest minutes required to reach the goal, go back to the start, then reach the goal
+/// again.
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let map = Map::from(input)?;
+    let initial_position = (1, 0);
+    let final_position = (map.width() - 2, map.height() - 1);
+
+    let (first_path, map) = find_shortest_path(
+        "Crossing the valley for the first time",
+        initial_position,
+        final_position,
+        map,
+    );
+    let (second_path, map) = find_shortest_path(
+        "Going back for the snacks",
+        final_position,
+        initial_position,
+        map,
+    );
+    let (third_path, _) = find_shortest_path(
+        "Crossing the valley again",
+        initial_position,
+        final_position,
+        map,
+    );
+    Ok(first_path + second_path + third_path)
+}
+
+#[test]
+fn tests() {
+    let example = include_str!("../example.txt");
+    assert_eq!(18, part1(example).unwrap());
+    assert_eq!(54, part2(example).unwrap());
+}
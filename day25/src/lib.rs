@@ -0,0 +1,95 @@
+use common::error::{ParseError, Source};
+
+const DAY: u8 = 25;
+const BASE: i64 = 5;
+
+fn from_snafu(source: &Source, string: &str) -> Result<i64, ParseError> {
+    string
+        .char_indices()
+        .rev()
+        .enumerate()
+        .map(|(position, (index, char))| {
+            Ok(match char {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(source.char_error(string, index, "a SNAFU digit")),
+            } * BASE.pow(position as u32))
+        })
+        .sum()
+}
+
+fn to_snafu(number: i64) -> String {
+    // Find the lowest snafu number that fits.
+    for position in 0_i32.. {
+        for digit in -2..=2 {
+            let value = digit * 5i64.pow(position as u32);
+            let max: i64 = value
+                + (0..position)
+                    .map(|position| 2 * BASE.pow(position as u32))
+                    .sum::<i64>();
+            let min: i64 = value
+                + (0..position)
+                    .map(|position| -2 * BASE.pow(position as u32))
+                    .sum::<i64>();
+            if min <= number && number <= max {
+                let char = match digit {
+                    2 => '2',
+                    1 => '1',
+                    0 => '0',
+                    -1 => '-',
+                    -2 => '=',
+                    _ => unreachable!(),
+                };
+                let remainder = number - value;
+                let remainder = if remainder == 0 {
+                    String::from("")
+                } else {
+                    to_snafu(remainder)
+                };
+                let mut result = String::from(char);
+                for _ in 0..(position - remainder.len() as i32) {
+                    result.push('0');
+                }
+                return result + &remainder.to_string();
+            }
+        }
+    }
+    unreachable!();
+}
+
+/// Returns the SNAFU number to enter on the console.
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let source = Source::new(DAY, input);
+    let result = input
+        .trim()
+        .split('\n')
+        .map(|line| from_snafu(&source, line.trim()))
+        .sum::<Result<i64, ParseError>>()?;
+    Ok(to_snafu(result))
+}
+
+#[test]
+fn tests() {
+    assert_eq!("0", to_snafu(0));
+    assert_eq!("1", to_snafu(1));
+    assert_eq!("2", to_snafu(2));
+    assert_eq!("1=", to_snafu(3));
+    assert_eq!("1-", to_snafu(4));
+    assert_eq!("10", to_snafu(5));
+    assert_eq!("11", to_snafu(6));
+    assert_eq!("12", to_snafu(7));
+    assert_eq!("2=", to_snafu(8));
+    assert_eq!("2-", to_snafu(9));
+    assert_eq!("20", to_snafu(10));
+    assert_eq!("1=0", to_snafu(15));
+    assert_eq!("1-0", to_snafu(20));
+    assert_eq!("1=11-2", to_snafu(2022));
+    assert_eq!("1-0---0", to_snafu(12345));
+    assert_eq!("1121-1110-1=0", to_snafu(314159265));
+
+    let example = include_str!("../example.txt");
+    assert_eq!("2=-1=0", part1(example).unwrap());
+}
@@ -0,0 +1,13 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns the calories carried by |size| elves, at least three.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            (0..rng.gen_range(1..=15))
+                .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
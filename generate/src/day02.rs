@@ -0,0 +1,12 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns |size| rounds of the strategy guide.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let opponent = (b'A' + rng.gen_range(0..3)) as char;
+            let response = (b'X' + rng.gen_range(0..3)) as char;
+            format!("{opponent} {response}\n")
+        })
+        .collect()
+}
@@ -0,0 +1,80 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Returns a compartment holding every one of |items| and as many more copies of them as needed
+/// to reach |length|.
+fn compartment(rng: &mut StdRng, items: &[u8], length: usize) -> Vec<u8> {
+    let mut compartment = items.to_vec();
+    while compartment.len() < length {
+        compartment.push(*items.choose(rng).unwrap());
+    }
+    compartment.shuffle(rng);
+    compartment
+}
+
+/// Returns the rucksacks of a group of three elves, sharing a single badge. Each rucksack has a
+/// single item in both of its compartments.
+fn group(rng: &mut StdRng) -> Vec<String> {
+    let mut items = ITEMS.to_vec();
+    items.shuffle(rng);
+    let badge = items[0];
+    // Every other item is only in some of the rucksacks, in a single compartment.
+    let others = &items[4..];
+
+    let mut halves: Vec<(Vec<u8>, Vec<u8>)> = (0..3)
+        .map(|index| {
+            let shared = if rng.gen_bool(0.2) {
+                badge
+            } else {
+                items[1 + index]
+            };
+            let (mut left, mut right) = (vec![shared], vec![shared]);
+            if shared != badge {
+                if rng.gen_bool(0.5) {
+                    left.push(badge);
+                } else {
+                    right.push(badge);
+                }
+            }
+            for &item in others {
+                match rng.gen_range(0..4) {
+                    0 => left.push(item),
+                    1 => right.push(item),
+                    _ => (),
+                }
+            }
+            (left, right)
+        })
+        .collect();
+
+    // Only the badge may be in every rucksack.
+    for item in others {
+        if halves
+            .iter()
+            .all(|(left, right)| left.contains(item) || right.contains(item))
+        {
+            let (left, right) = &mut halves[0];
+            left.retain(|other| other != item);
+            right.retain(|other| other != item);
+        }
+    }
+
+    halves
+        .into_iter()
+        .map(|(left, right)| {
+            let length = left.len().max(right.len()) + rng.gen_range(0..8);
+            let mut rucksack = compartment(rng, &left, length);
+            rucksack.extend(compartment(rng, &right, length));
+            String::from_utf8(rucksack).unwrap()
+        })
+        .collect()
+}
+
+/// Returns the rucksacks of |size| groups of three elves.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .flat_map(|_| group(rng))
+        .map(|rucksack| rucksack + "\n")
+        .collect()
+}
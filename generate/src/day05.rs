@@ -0,0 +1,46 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns up to nine stacks of crates and |size| moves, which never empty a stack.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = rng.gen_range(3..=9);
+    let mut stacks: Vec<Vec<char>> = (0..count)
+        .map(|index| {
+            let height = rng.gen_range(if index == 0 { 2 } else { 1 }..=8);
+            (0..height)
+                .map(|_| (b'A' + rng.gen_range(0..26)) as char)
+                .collect()
+        })
+        .collect();
+
+    let mut drawing = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("[{label}]"),
+                None => String::from("   "),
+            })
+            .collect();
+        drawing += &(row.join(" ") + "\n");
+    }
+    let numbers: Vec<String> = (1..=count).map(|number| format!(" {number} ")).collect();
+    drawing += &(numbers.join(" ") + "\n\n");
+
+    // As some stack has more than one crate, there is always a possible move.
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.gen_range(0..count);
+            if stacks[from].len() > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.gen_range(1..count)) % count;
+        let moved = rng.gen_range(1..stacks[from].len());
+        let at = stacks[from].len() - moved;
+        let crates = stacks[from].split_off(at);
+        stacks[to].extend(crates);
+        drawing += &format!("move {moved} from {} to {}\n", from + 1, to + 1);
+    }
+    drawing
+}
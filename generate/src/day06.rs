@@ -0,0 +1,15 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// Returns a datastream of about |size| characters, which ends with fourteen different ones so that
+/// both markers are found.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    // Few letters make the markers appear late.
+    let alphabet = rng.gen_range(2..=26);
+    let mut stream: Vec<u8> = (0..size)
+        .map(|_| b'a' + rng.gen_range(0..alphabet))
+        .collect();
+    letters.shuffle(rng);
+    stream.extend(&letters[..14]);
+    String::from_utf8(stream).unwrap() + "\n"
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, Rng};
+
+/// The total size is between these two, so that some directory has to be deleted.
+const MIN_TOTAL: u64 = 40_000_001;
+const MAX_TOTAL: u64 = 70_000_000;
+
+#[derive(Default)]
+struct Directory {
+    files: Vec<(String, u64)>,
+    /// The names of the directories inside, along with their index.
+    directories: Vec<(String, usize)>,
+}
+
+/// Returns a name that is not in |names| yet, and adds it to them.
+fn unique_name(rng: &mut StdRng, names: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(1..=8))
+            .map(|_| (b'a' + rng.gen_range(0..26)) as char)
+            .collect();
+        if extension {
+            name.push('.');
+            name.extend((0..3).map(|_| (b'a' + rng.gen_range(0..26)) as char));
+        }
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Writes the commands listing |directory| and everything inside it.
+fn explore(directories: &[Directory], index: usize, output: &mut String) {
+    *output += "$ ls\n";
+    let directory = &directories[index];
+    for (name, _) in &directory.directories {
+        *output += &format!("dir {name}\n");
+    }
+    for (name, size) in &directory.files {
+        *output += &format!("{size} {name}\n");
+    }
+    for (name, inner) in &directory.directories {
+        *output += &format!("$ cd {name}\n");
+        explore(directories, *inner, output);
+        *output += "$ cd ..\n";
+    }
+}
+
+/// Returns the terminal output of exploring |size| directories, whose files take between 40 and
+/// 70 million in total.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut directories: Vec<Directory> = (0..size).map(|_| Directory::default()).collect();
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new(); size];
+    for index in 1..size {
+        let parent = rng.gen_range(0..index);
+        let name = unique_name(rng, &mut names[parent], false);
+        directories[parent].directories.push((name, index));
+    }
+    for (directory, names) in directories.iter_mut().zip(names.iter_mut()) {
+        for _ in 0..rng.gen_range(0..=4) {
+            let extension = rng.gen_bool(0.5);
+            let name = unique_name(rng, names, extension);
+            directory.files.push((name, rng.gen_range(1000..=300_000)));
+        }
+    }
+
+    // Shrink the files to make room for a last one, which brings the total within bounds.
+    let total: u64 = directories
+        .iter()
+        .flat_map(|directory| &directory.files)
+        .map(|(_, size)| size)
+        .sum();
+    if total >= MIN_TOTAL {
+        for (_, size) in directories
+            .iter_mut()
+            .flat_map(|directory| &mut directory.files)
+        {
+            *size = (*size * (MIN_TOTAL - 1) / total).max(1);
+        }
+    }
+    let total: u64 = directories
+        .iter()
+        .flat_map(|directory| &directory.files)
+        .map(|(_, size)| size)
+        .sum();
+    let index = rng.gen_range(0..size);
+    let name = unique_name(rng, &mut names[index], true);
+    let last = rng.gen_range(MIN_TOTAL..=MAX_TOTAL) - total;
+    directories[index].files.push((name, last));
+
+    let mut output = String::from("$ cd /\n");
+    explore(&directories, 0, &mut output);
+    output
+}
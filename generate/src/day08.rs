@@ -0,0 +1,13 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns a square of |size| by |size| tree heights.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                .chain(std::iter::once('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
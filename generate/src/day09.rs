@@ -0,0 +1,11 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns |size| motions of the head of the rope.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = ["R", "L", "U", "D"][rng.gen_range(0..4)];
+            format!("{direction} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
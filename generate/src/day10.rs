@@ -0,0 +1,24 @@
+use rand::{rngs::StdRng, Rng};
+
+/// The screen is drawn over exactly this many cycles.
+const CYCLES: usize = 240;
+
+/// Returns a program running for exactly 240 cycles, whose sprite stays on the screen. The |size|
+/// is ignored, as the screen has a fixed size.
+pub fn generate(rng: &mut StdRng, _size: usize) -> String {
+    let mut program = String::new();
+    let mut cycles = 0;
+    let mut register = 1;
+    while cycles < CYCLES {
+        if cycles + 2 <= CYCLES && rng.gen_bool(0.7) {
+            let operand = rng.gen_range((-1 - register).max(-20)..=(40 - register).min(20));
+            program += &format!("addx {operand}\n");
+            register += operand;
+            cycles += 2;
+        } else {
+            program += "noop\n";
+            cycles += 1;
+        }
+    }
+    program
+}
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const PRIMES: [i64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+const ROUNDS: usize = 20;
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(i64),
+    Multiply(i64),
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, old: i64) -> Option<i64> {
+        match self {
+            Operation::Add(constant) => old.checked_add(*constant),
+            Operation::Multiply(constant) => old.checked_mul(*constant),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    divisor: i64,
+    /// The monkeys thrown to when the test is true and when it is false.
+    targets: (usize, usize),
+}
+
+fn monkey(rng: &mut StdRng, index: usize, count: usize, divisor: i64) -> Monkey {
+    let mut other = || (index + rng.gen_range(1..count)) % count;
+    let targets = (other(), other());
+    let operation = match rng.gen_range(0..6) {
+        0 => Operation::Square,
+        1..=3 => Operation::Add(rng.gen_range(1..=8)),
+        _ => Operation::Multiply(rng.gen_range(2..=19)),
+    };
+    Monkey {
+        items: (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range(50..=99))
+            .collect(),
+        operation,
+        divisor,
+        targets,
+    }
+}
+
+/// Returns whether the worry levels stay within an i64 for the rounds of part 1, which lets them
+/// grow without bound.
+fn is_bounded(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<VecDeque<i64>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().copied().collect())
+        .collect();
+    for _ in 0..ROUNDS {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            while let Some(item) = items[index].pop_front() {
+                let Some(item) = monkey.operation.apply(item) else {
+                    return false;
+                };
+                let item = item / 3;
+                let target = if item % monkey.divisor == 0 {
+                    monkey.targets.0
+                } else {
+                    monkey.targets.1
+                };
+                items[target].push_back(item);
+            }
+        }
+    }
+    true
+}
+
+/// Returns the notes of up to eight monkeys, as there are as many prime divisors. Their worry
+/// levels never overflow in part 1.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, PRIMES.len());
+    let monkeys = loop {
+        let mut divisors = PRIMES.to_vec();
+        divisors.shuffle(rng);
+        let monkeys: Vec<Monkey> = (0..count)
+            .map(|index| monkey(rng, index, count, divisors[index]))
+            .collect();
+        if is_bounded(&monkeys) {
+            break monkeys;
+        }
+    };
+
+    let notes: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(index, monkey)| {
+            let items: Vec<String> = monkey.items.iter().map(i64::to_string).collect();
+            let operation = match monkey.operation {
+                Operation::Add(constant) => format!("old + {constant}"),
+                Operation::Multiply(constant) => format!("old * {constant}"),
+                Operation::Square => String::from("old * old"),
+            };
+            format!(
+                "Monkey {index}:\n  Starting items: {}\n  Operation: new = {operation}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                items.join(", "),
+                monkey.divisor,
+                monkey.targets.0,
+                monkey.targets.1
+            )
+        })
+        .collect();
+    notes.join("\n")
+}
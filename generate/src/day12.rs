@@ -0,0 +1,44 @@
+use rand::{rngs::StdRng, Rng};
+
+/// The height of the best signal, and the distance needed to climb from a up to it.
+const TOP: usize = 25;
+
+fn distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// Returns a height map |size| squares wide, at least wide enough to climb from a to z.
+///
+/// Hills rise by one square at every step towards their summit, and the start sits in a hole
+/// that rises the same way, so that every square can be reached from every other.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(TOP + 5);
+    let height = (size / 3).max(5);
+    let start = (0, rng.gen_range(0..height));
+    let end = (rng.gen_range(TOP + 1..width), rng.gen_range(0..height));
+    // The summits, along with how far below z they are.
+    let mut summits = vec![(end, 0)];
+    for _ in 0..width * height / 100 {
+        let summit = (rng.gen_range(0..width), rng.gen_range(0..height));
+        summits.push((summit, rng.gen_range(1..=10)));
+    }
+
+    let mut map = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let hills = summits
+                .iter()
+                .map(|(summit, depth)| TOP.saturating_sub(depth + distance((x, y), *summit)))
+                .max()
+                .unwrap();
+            let square = match (x, y) {
+                point if point == start => 'S',
+                point if point == end => 'E',
+                point => (b'a' + hills.min(distance(point, start)) as u8) as char,
+            };
+            map.push(square);
+        }
+        map.push('\n');
+    }
+    map
+}
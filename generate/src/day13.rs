@@ -0,0 +1,23 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns a packet, a list of integers and lists nested at most |depth| times.
+fn packet(rng: &mut StdRng, depth: usize) -> String {
+    let entries: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth > 0 && rng.gen_bool(0.3) {
+                packet(rng, depth - 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+/// Returns |size| pairs of packets.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}\n", packet(rng, 4), packet(rng, 4)))
+        .collect();
+    pairs.join("\n")
+}
@@ -0,0 +1,30 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Where the sand comes from.
+const SOURCE: i64 = 500;
+
+/// Returns a path of rock, turning at every point.
+fn path(rng: &mut StdRng, depth: i64) -> String {
+    let mut point = (
+        SOURCE + rng.gen_range(-depth..=depth),
+        rng.gen_range(1..=depth),
+    );
+    let mut points = vec![point];
+    for index in 0..rng.gen_range(1..=5) {
+        let length = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        if index % 2 == 0 {
+            point.0 = (point.0 + length).max(1);
+        } else {
+            point.1 = (point.1 + length).max(1);
+        }
+        points.push(point);
+    }
+    let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+    points.join(" -> ") + "\n"
+}
+
+/// Returns |size| paths of rock below the source of the sand.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let depth = (size as i64 * 2).max(10);
+    (0..size).map(|_| path(rng, depth)).collect()
+}
@@ -0,0 +1,54 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// The distress beacon is somewhere in the square from 0 to this, on both axes.
+const SEARCH_RANGE: i64 = 4_000_000;
+/// How far the sensors around the distress beacon are from it, on both axes.
+const OFFSET: i64 = SEARCH_RANGE / 2 + 1;
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn report(sensor: (i64, i64), beacon: (i64, i64)) -> String {
+    format!(
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+        sensor.0, sensor.1, beacon.0, beacon.1
+    )
+}
+
+/// Returns the reports of |size| sensors, along with four more that hide a single distress beacon
+/// within the search range.
+///
+/// Each of the four sensors sits diagonally from the distress beacon, and reaches every point but
+/// it within the search range on its side.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let hidden = (
+        rng.gen_range(0..=SEARCH_RANGE),
+        rng.gen_range(0..=SEARCH_RANGE),
+    );
+    let mut reports = Vec::new();
+    for (x, y) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = (hidden.0 + x * OFFSET, hidden.1 + y * OFFSET);
+        let beacon = (sensor.0 + x * (2 * OFFSET - 1), sensor.1);
+        reports.push(report(sensor, beacon));
+    }
+    while reports.len() < size + 4 {
+        let sensor = (
+            rng.gen_range(0..=SEARCH_RANGE),
+            rng.gen_range(0..=SEARCH_RANGE),
+        );
+        let reach = distance(sensor, hidden);
+        if reach < 2 {
+            continue;
+        }
+        let range = rng.gen_range(1..reach);
+        let x = rng.gen_range(0..=range);
+        let beacon = (
+            sensor.0 + x * if rng.gen_bool(0.5) { 1 } else { -1 },
+            sensor.1 + (range - x) * if rng.gen_bool(0.5) { 1 } else { -1 },
+        );
+        reports.push(report(sensor, beacon));
+    }
+    reports.shuffle(rng);
+    reports.concat()
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// The most valves with a flow, beyond which the solvers take too long.
+const MAX_FLOWS: usize = 15;
+
+/// Returns |size| valves, all connected to AA, about half of them with a flow.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = vec![String::from("AA")];
+    while names.len() < count {
+        let name: String = (0..2)
+            .map(|_| (b'A' + rng.gen_range(0..26)) as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // A tree spanning every valve, with a few more tunnels.
+    let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.gen_range(0..valve));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut flows = vec![0; count];
+    let mut valves: Vec<usize> = (1..count).collect();
+    valves.shuffle(rng);
+    for valve in valves.into_iter().take((count / 2).min(MAX_FLOWS)) {
+        flows[valve] = rng.gen_range(1..=25);
+    }
+
+    let mut scan: Vec<String> = (0..count)
+        .map(|valve| {
+            let mut others: Vec<&str> = tunnels[valve]
+                .iter()
+                .map(|other| names[*other].as_str())
+                .collect();
+            others.shuffle(rng);
+            let tunnels = match others[..] {
+                [other] => format!("tunnel leads to valve {other}"),
+                _ => format!("tunnels lead to valves {}", others.join(", ")),
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels}\n",
+                names[valve], flows[valve]
+            )
+        })
+        .collect();
+    scan.shuffle(rng);
+    scan.concat()
+}
@@ -0,0 +1,9 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns a pattern of |size| jets.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| if rng.gen_bool(0.5) { '<' } else { '>' })
+        .chain(std::iter::once('\n'))
+        .collect()
+}
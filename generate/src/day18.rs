@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Returns |size| cubes, packed tightly enough for some to enclose air pockets.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = ((size * 3 / 2) as f64).cbrt().ceil() as i64;
+    let mut cubes = HashSet::new();
+    let mut output = String::new();
+    while cubes.len() < size {
+        let cube = (
+            rng.gen_range(0..side),
+            rng.gen_range(0..side),
+            rng.gen_range(0..side),
+        );
+        if cubes.insert(cube) {
+            output += &format!("{},{},{}\n", cube.0, cube.1, cube.2);
+        }
+    }
+    output
+}
@@ -0,0 +1,20 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns |size| blueprints, costing about as much as in the puzzle.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (1..=size)
+        .map(|index| {
+            format!(
+                "Blueprint {index}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.\n",
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+            )
+        })
+        .collect()
+}
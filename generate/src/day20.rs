@@ -0,0 +1,19 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns |size| numbers to mix, at least three, with a single zero among them.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(3);
+    let zero = rng.gen_range(0..size);
+    (0..size)
+        .map(|index| {
+            let number = if index == zero {
+                0
+            } else if rng.gen_bool(0.5) {
+                rng.gen_range(1..=10_000)
+            } else {
+                -rng.gen_range(1..=10_000)
+            };
+            format!("{number}\n")
+        })
+        .collect()
+}
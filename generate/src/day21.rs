@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// The numbers yelled stay below this, so that they are exact as floating point numbers.
+const LIMIT: i64 = 1_000_000_000_000;
+
+struct Monkeys<'a> {
+    rng: &'a mut StdRng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Monkeys<'_> {
+    /// Adds a monkey with |job|, returning its name.
+    fn add(&mut self, job: String) -> String {
+        let name = loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + self.rng.gen_range(0..26)) as char)
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.jobs.push(format!("{name}: {job}"));
+        name
+    }
+
+    /// Adds a monkey yelling |left| |operation| |right|, swapping the two if |swap|.
+    fn operation(&mut self, left: &str, operation: char, right: &str, swap: bool) -> String {
+        if swap {
+            self.add(format!("{right} {operation} {left}"))
+        } else {
+            self.add(format!("{left} {operation} {right}"))
+        }
+    }
+
+    /// Adds monkeys that eventually yell |value|, waiting for each other at most |depth| times,
+    /// and returns the name of the one yelling it.
+    fn constant(&mut self, value: i64, depth: usize) -> String {
+        if value > 0 && (depth == 0 || self.rng.gen_bool(0.3)) {
+            return self.add(value.to_string());
+        }
+        let depth = depth.saturating_sub(1);
+        let small = self.rng.gen_range(1..=10);
+        let swap = self.rng.gen_bool(0.5);
+        match self.rng.gen_range(0..4) {
+            0 if value > 1 => {
+                let part = self.rng.gen_range(1..value);
+                let left = self.constant(part, depth);
+                let right = self.constant(value - part, depth);
+                self.operation(&left, '+', &right, swap)
+            }
+            1 if small > 1 && value % small == 0 => {
+                let left = self.constant(small, depth);
+                let right = self.constant(value / small, depth);
+                self.operation(&left, '*', &right, swap)
+            }
+            2 if small > 1 && (value * small).abs() < LIMIT => {
+                let left = self.constant(value * small, depth);
+                let right = self.constant(small, depth);
+                self.operation(&left, '/', &right, false)
+            }
+            // Two leaves can yell any number, even one below 1, as their difference.
+            _ => {
+                let left = self.add((value.max(0) + small).to_string());
+                let right = self.add((value.max(0) + small - value).to_string());
+                self.operation(&left, '-', &right, false)
+            }
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a.abs(),
+        _ => gcd(b, a % b),
+    }
+}
+
+/// Returns the jobs of the monkeys, where the human is |size| monkeys away from the root.
+///
+/// Both the number yelled by the root and the one the human has to yell are integers, as every
+/// division along the way is exact.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // The number the human yells in part 1, and the one it has to yell in part 2.
+    let first: i64 = rng.gen_range(1..=1000);
+    let second: i64 = rng.gen_range(1..=1_000_000);
+    let mut monkeys = Monkeys {
+        rng,
+        names: HashSet::new(),
+        jobs: vec![format!("humn: {first}")],
+    };
+
+    let mut path = (String::from("humn"), first, second);
+    for _ in 0..size {
+        let (name, first, second) = &path;
+        let (first, second) = (*first, *second);
+        let small = monkeys.rng.gen_range(2..=10);
+        let swap = monkeys.rng.gen_bool(0.5);
+        let divisor = gcd(first, second);
+        path = match monkeys.rng.gen_range(0..4) {
+            1 if (first * small).abs() < LIMIT && (second * small).abs() < LIMIT => {
+                let other = monkeys.constant(small, 2);
+                let name = monkeys.operation(name, '*', &other, swap);
+                (name, first * small, second * small)
+            }
+            2 if divisor % small == 0 => {
+                let other = monkeys.constant(small, 2);
+                let name = monkeys.operation(name, '/', &other, false);
+                (name, first / small, second / small)
+            }
+            3 => {
+                let constant = monkeys.rng.gen_range(1..=1000);
+                let other = monkeys.constant(constant, 2);
+                let name = monkeys.operation(name, '-', &other, swap);
+                if swap {
+                    (name, constant - first, constant - second)
+                } else {
+                    (name, first - constant, second - constant)
+                }
+            }
+            _ => {
+                let constant = monkeys.rng.gen_range(1..=1000);
+                let other = monkeys.constant(constant, 2);
+                let name = monkeys.operation(name, '+', &other, swap);
+                (name, first + constant, second + constant)
+            }
+        };
+    }
+
+    // The human is always on the left of the root.
+    let (name, _, second) = path;
+    let other = monkeys.constant(second, 3);
+    monkeys.jobs.push(format!("root: {name} + {other}"));
+    monkeys.jobs.shuffle(monkeys.rng);
+    monkeys.jobs.iter().map(|job| format!("{job}\n")).collect()
+}
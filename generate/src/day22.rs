@@ -0,0 +1,65 @@
+use rand::{rngs::StdRng, Rng};
+
+/// The eleven nets of a cube, as the columns and rows of their faces.
+const NETS: [[(i32, i32); 6]; 11] = [
+    [(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (0, 2)],
+    [(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (1, 2)],
+    [(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (2, 2)],
+    [(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (3, 2)],
+    [(1, 0), (0, 1), (1, 1), (2, 1), (3, 1), (1, 2)],
+    [(1, 0), (0, 1), (1, 1), (2, 1), (3, 1), (2, 2)],
+    [(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (1, 2)],
+    [(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (2, 2)],
+    [(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (3, 2)],
+    [(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (3, 2)],
+    [(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (4, 1)],
+];
+
+/// Returns the faces of a random net, turned and flipped at random.
+fn net(rng: &mut StdRng) -> Vec<(i32, i32)> {
+    let mut faces = NETS[rng.gen_range(0..NETS.len())].to_vec();
+    for _ in 0..rng.gen_range(0..4) {
+        faces = faces.into_iter().map(|(x, y)| (y, -x)).collect();
+    }
+    if rng.gen_bool(0.5) {
+        faces = faces.into_iter().map(|(x, y)| (-x, y)).collect();
+    }
+    let left = faces.iter().map(|face| face.0).min().unwrap();
+    let top = faces.iter().map(|face| face.1).min().unwrap();
+    faces
+        .into_iter()
+        .map(|(x, y)| (x - left, y - top))
+        .collect()
+}
+
+/// Returns a map folding into a cube whose faces are |size| tiles wide, followed by a path.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let faces = net(rng);
+    let size = size.max(2) as i32;
+    let rows = faces.iter().map(|face| face.1).max().unwrap() + 1;
+
+    let mut map = String::new();
+    for y in 0..rows * size {
+        let columns: Vec<i32> = faces
+            .iter()
+            .filter(|face| face.1 == y / size)
+            .map(|face| face.0)
+            .collect();
+        let left = columns.iter().min().unwrap() * size;
+        let right = (columns.iter().max().unwrap() + 1) * size;
+        map += &" ".repeat(left as usize);
+        for x in left..right {
+            // The path starts on the leftmost tile of the top row.
+            let wall = !(y == 0 && x == left) && rng.gen_bool(0.15);
+            map.push(if wall { '#' } else { '.' });
+        }
+        map.push('\n');
+    }
+
+    let mut path = rng.gen_range(1..=2 * size).to_string();
+    for _ in 0..size * 2 {
+        path.push(if rng.gen_bool(0.5) { 'L' } else { 'R' });
+        path += &rng.gen_range(1..=2 * size).to_string();
+    }
+    format!("{map}\n{path}\n")
+}
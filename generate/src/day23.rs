@@ -0,0 +1,18 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Returns a square grove |size| tiles wide, with at least one elf.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let elf = (rng.gen_range(0..size), rng.gen_range(0..size));
+    let mut grove = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            grove.push(if (x, y) == elf || rng.gen_bool(0.4) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        grove.push('\n');
+    }
+    grove
+}
@@ -0,0 +1,104 @@
+use std::collections::{HashSet, VecDeque};
+
+use rand::{rngs::StdRng, Rng};
+
+/// The inside of the valley, without its walls.
+struct Valley {
+    width: usize,
+    height: usize,
+    /// Every tile by row then column at the start, either a blizzard or open.
+    tiles: Vec<Vec<char>>,
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    a / gcd(a, b) * b
+}
+
+impl Valley {
+    /// Returns whether a blizzard covers the tile at |x|, |y| after |minute| minutes.
+    fn is_covered(&self, x: usize, y: usize, minute: usize) -> bool {
+        let (width, height) = (self.width, self.height);
+        self.tiles[y][(x + width - minute % width) % width] == '>'
+            || self.tiles[y][(x + minute) % width] == '<'
+            || self.tiles[(y + height - minute % height) % height][x] == 'v'
+            || self.tiles[(y + minute) % height][x] == '^'
+    }
+
+    /// Returns whether the expedition can go from the entrance above the top left tile to the exit
+    /// below the bottom right tile, or the other way around if |back|.
+    fn is_crossable(&self, back: bool) -> bool {
+        let period = lcm(self.width, self.height);
+        let (width, height) = (self.width as i64, self.height as i64);
+        // The entrance and the exit are one row outside of the valley.
+        let (mut start, mut end) = ((0, -1), (width - 1, height));
+        if back {
+            (start, end) = (end, start);
+        }
+        let mut seen = HashSet::from([(start, 0)]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some(((x, y), minute)) = queue.pop_front() {
+            let next = (minute + 1) % period;
+            for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let tile = (x + dx, y + dy);
+                if tile == end {
+                    return true;
+                }
+                let open = tile == start
+                    || (0..width).contains(&tile.0)
+                        && (0..height).contains(&tile.1)
+                        && !self.is_covered(tile.0 as usize, tile.1 as usize, next);
+                if open && seen.insert((tile, next)) {
+                    queue.push_back((tile, next));
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Returns a valley |size| tiles wide, which can be crossed both ways.
+///
+/// No blizzard goes up or down in the columns of the entrance and the exit, so that the expedition
+/// is always safe there.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(2);
+    let height = (size / 4).max(2);
+    let valley = loop {
+        let tiles = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|x| {
+                        let vertical = x != 0 && x != width - 1;
+                        match rng.gen_range(0..8) {
+                            0 => '>',
+                            1 => '<',
+                            2 if vertical => '^',
+                            3 if vertical => 'v',
+                            _ => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let valley = Valley {
+            width,
+            height,
+            tiles,
+        };
+        if valley.is_crossable(false) && valley.is_crossable(true) {
+            break valley;
+        }
+    };
+
+    let mut map = format!("#.{}\n", "#".repeat(width));
+    for row in &valley.tiles {
+        map += &format!("#{}#\n", row.iter().collect::<String>());
+    }
+    map + &format!("{}.#\n", "#".repeat(width))
+}
@@ -0,0 +1,16 @@
+use rand::{rngs::StdRng, Rng};
+
+const DIGITS: [char; 5] = ['=', '-', '0', '1', '2'];
+
+/// Returns |size| SNAFU numbers, none of them starting with a zero.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut number = String::from(['1', '2'][rng.gen_range(0..2)]);
+            for _ in 0..rng.gen_range(0..15) {
+                number.push(DIGITS[rng.gen_range(0..5)]);
+            }
+            number + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Random puzzle inputs for every day, to test and benchmark the solvers beyond their single input.
+//!
+//! Every generator takes a |size|, which scales the input roughly linearly (the number of lines,
+//! monkeys, valves, ...), and a random number generator. The same seed always gives the same
+//! input.
+
+use rand::{rngs::StdRng, SeedableRng};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// Returns a random input for |day|, or None if there is no such day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let generator = match day {
+        1 => day01::generate,
+        2 => day02::generate,
+        3 => day03::generate,
+        4 => day04::generate,
+        5 => day05::generate,
+        6 => day06::generate,
+        7 => day07::generate,
+        8 => day08::generate,
+        9 => day09::generate,
+        10 => day10::generate,
+        11 => day11::generate,
+        12 => day12::generate,
+        13 => day13::generate,
+        14 => day14::generate,
+        15 => day15::generate,
+        16 => day16::generate,
+        17 => day17::generate,
+        18 => day18::generate,
+        19 => day19::generate,
+        20 => day20::generate,
+        21 => day21::generate,
+        22 => day22::generate,
+        23 => day23::generate,
+        24 => day24::generate,
+        25 => day25::generate,
+        _ => return None,
+    };
+    Some(generator(&mut StdRng::seed_from_u64(seed), size.max(1)))
+}
+
+#[test]
+fn tests() {
+    for day in 1..=25 {
+        assert_eq!(generate(day, 10, 7), generate(day, 10, 7));
+        assert_ne!(generate(day, 10, 7), generate(day, 10, 8));
+    }
+    assert_eq!(generate(26, 10, 7), None);
+}